@@ -0,0 +1,43 @@
+use azure_search_rs::{
+    client::AzureSearchClient,
+    credential::{ClientSecretCredential, Credential, WorkloadIdentityCredential},
+    models::SearchRequest,
+    operations::search::SearchTrait,
+};
+use std::env;
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let service_name = env::var("AZURE_SEARCH_SERVICE_NAME")
+        .expect("AZURE_SEARCH_SERVICE_NAME environment variable not set");
+    let index_name = env::var("AZURE_SEARCH_INDEX_NAME")
+        .expect("AZURE_SEARCH_INDEX_NAME environment variable not set");
+    let endpoint = format!("https://{}.search.windows.net", service_name);
+
+    // Prefer workload identity (e.g. on AKS), fall back to a client secret
+    let credential: Credential = match WorkloadIdentityCredential::from_env() {
+        Ok(credential) => credential.into(),
+        Err(_) => ClientSecretCredential::new(
+            env::var("AZURE_TENANT_ID")?,
+            env::var("AZURE_CLIENT_ID")?,
+            env::var("AZURE_CLIENT_SECRET")?,
+        )
+        .into(),
+    };
+
+    let client = AzureSearchClient::new_with_credential(endpoint, credential, "2024-07-01", None)?;
+
+    let results = client
+        .search::<serde_json::Value>(
+            &index_name,
+            &SearchRequest {
+                search: Some("*".to_string()),
+                top: Some(5),
+                ..Default::default()
+            },
+        )
+        .await?;
+
+    println!("Found {} results", results.value.len());
+    Ok(())
+}
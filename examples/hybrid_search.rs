@@ -32,8 +32,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         fields: vec!["description_vector".to_string()],
         k: 10,
         weight: None,
-    }
-    .into()];
+    }];
 
     // Execute hybrid search
     let results = client
@@ -53,31 +52,27 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         println!();
     }
 
-    let results: SearchResponse<Hotel> = client
-        .search(
-            &index_name,
-            &SearchRequest {
-                count: true,
-                select: Some(vec!["id".to_string(), "description".to_string()]),
-                top: Some(10),
-                skip: Some(0),
-                search: Some(text_query.to_string()),
-                query_type: Some(QueryType::Simple),
-                semantic_configuration: Some(semantic_config.to_string()),
-                vector_queries: Some(vector_queries),
-                search_mode: Some(SearchMode::Any),
-                filter: Some("description eq 'luxury hotel'".to_string()),
-                orderby: Some("description desc".to_string()),
-                facets: Some(vec!["description".to_string()]),
-                highlight: Some(vec!["description".to_string()]),
-                highlight_pre_tag: Some("<b>".to_string()),
-                highlight_post_tag: Some("</b>".to_string()),
-                minimum_coverage: Some(100),
-                search_fields: Some(vec!["description".to_string()]),
-                session_id: Some("session-id".to_string()),
-            },
-        )
-        .await?;
+    let request = SearchRequest::builder()
+        .count(true)
+        .select(["id", "description"])
+        .top(10)
+        .skip(0)
+        .search(text_query)
+        .query_type(QueryType::Simple)
+        .semantic_configuration(semantic_config)
+        .search_mode(SearchMode::Any)
+        .filter("description eq 'luxury hotel'")
+        .orderby("description desc")
+        .facet("description")
+        .highlight(["description"])
+        .highlight_tags("<b>", "</b>")
+        .minimum_coverage(100)
+        .search_fields(["description"])
+        .session_id("session-id")
+        .vector_queries(vector_queries)
+        .build()?;
+
+    let results: SearchResponse<Hotel> = client.search(&index_name, &request).await?;
 
     println!("Found {} results:", results.value.len());
     for search_result in results.value {
@@ -1,5 +1,6 @@
-use azure_search_rs::client::AzureSearchClient;
+use azure_search_rs::client::AzureSearchClientBuilder;
 use azure_search_rs::operations::document::DocumentTrait;
+use azure_search_rs::ApiVersion;
 
 use serde::{Deserialize, Serialize};
 
@@ -12,13 +13,14 @@ struct Hotel {
 
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
-    // Create client instance
-    let service_name = std::env::var("AZURE_SEARCH_SERVICE_NAME")?;
-    let endpoint = format!("https://{}.search.windows.net", service_name);
+    // Create client instance from AZURE_SEARCH_SERVICE_NAME and AZURE_SEARCH_API_KEY
     let index_name = std::env::var("AZURE_SEARCH_INDEX_NAME")?;
-    let api_key = std::env::var("AZURE_SEARCH_API_KEY")?;
 
-    let client = AzureSearchClient::new(&endpoint, &api_key, "2024-07-01", None)?;
+    let client = AzureSearchClientBuilder::from_env()?
+        .api_version(ApiVersion::V2024_07_01)
+        .timeout(std::time::Duration::from_secs(30))
+        .user_agent_suffix("put-document-example")
+        .build()?;
 
     // Prepare test documents
     let documents = vec![
@@ -35,9 +37,18 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     ];
 
     // Upload documents
-    client.put_documents(&index_name, documents).await?;
-
-    println!("Documents uploaded successfully");
+    let result = client.put_documents(&index_name, documents).await?;
+
+    for failed in result.failed() {
+        println!(
+            "Document {} failed ({}): {:?}",
+            failed.key, failed.status_code, failed.error_message
+        );
+    }
+    println!(
+        "{} documents uploaded successfully",
+        result.succeeded().count()
+    );
 
     //get documents
     let document: Hotel = client.get_document(&index_name, "1").await?;
@@ -60,7 +60,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
             &index_name,
             "luxury hotel",
             "my-semantic-config",
-            vec![text_query.into()],
+            vec![text_query],
         )
         .await?;
     print_results(&hybrid_results);
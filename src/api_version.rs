@@ -0,0 +1,133 @@
+use std::fmt;
+use std::str::FromStr;
+
+/// Azure AI Search REST API version
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub enum ApiVersion {
+    /// 2023-11-01
+    V2023_11_01,
+    /// 2024-05-01-preview
+    V2024_05_01Preview,
+    /// 2024-07-01
+    #[default]
+    V2024_07_01,
+    /// 2024-09-01-preview
+    V2024_09_01Preview,
+    /// 2024-11-01-preview
+    V2024_11_01Preview,
+    /// 2025-03-01-preview
+    V2025_03_01Preview,
+    /// 2025-05-01-preview
+    V2025_05_01Preview,
+    /// 2025-08-01-preview
+    V2025_08_01Preview,
+    /// 2025-09-01
+    V2025_09_01,
+    /// Any other version string, passed through unchanged
+    Custom(String),
+}
+
+impl ApiVersion {
+    /// The value sent in the `api-version` query parameter
+    pub fn as_str(&self) -> &str {
+        match self {
+            ApiVersion::V2023_11_01 => "2023-11-01",
+            ApiVersion::V2024_05_01Preview => "2024-05-01-preview",
+            ApiVersion::V2024_07_01 => "2024-07-01",
+            ApiVersion::V2024_09_01Preview => "2024-09-01-preview",
+            ApiVersion::V2024_11_01Preview => "2024-11-01-preview",
+            ApiVersion::V2025_03_01Preview => "2025-03-01-preview",
+            ApiVersion::V2025_05_01Preview => "2025-05-01-preview",
+            ApiVersion::V2025_08_01Preview => "2025-08-01-preview",
+            ApiVersion::V2025_09_01 => "2025-09-01",
+            ApiVersion::Custom(version) => version,
+        }
+    }
+
+    /// Whether this is a preview version
+    pub fn is_preview(&self) -> bool {
+        self.as_str().ends_with("-preview")
+    }
+}
+
+impl fmt::Display for ApiVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for ApiVersion {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "2023-11-01" => ApiVersion::V2023_11_01,
+            "2024-05-01-preview" => ApiVersion::V2024_05_01Preview,
+            "2024-07-01" => ApiVersion::V2024_07_01,
+            "2024-09-01-preview" => ApiVersion::V2024_09_01Preview,
+            "2024-11-01-preview" => ApiVersion::V2024_11_01Preview,
+            "2025-03-01-preview" => ApiVersion::V2025_03_01Preview,
+            "2025-05-01-preview" => ApiVersion::V2025_05_01Preview,
+            "2025-08-01-preview" => ApiVersion::V2025_08_01Preview,
+            "2025-09-01" => ApiVersion::V2025_09_01,
+            other => ApiVersion::Custom(other.to_string()),
+        })
+    }
+}
+
+impl From<&str> for ApiVersion {
+    fn from(s: &str) -> Self {
+        match s.parse() {
+            Ok(version) => version,
+            Err(never) => match never {},
+        }
+    }
+}
+
+impl From<String> for ApiVersion {
+    fn from(s: String) -> Self {
+        ApiVersion::from(s.as_str())
+    }
+}
+
+impl From<&String> for ApiVersion {
+    fn from(s: &String) -> Self {
+        ApiVersion::from(s.as_str())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn known_versions_round_trip() {
+        for version in [
+            ApiVersion::V2023_11_01,
+            ApiVersion::V2024_05_01Preview,
+            ApiVersion::V2024_07_01,
+            ApiVersion::V2024_09_01Preview,
+            ApiVersion::V2024_11_01Preview,
+            ApiVersion::V2025_03_01Preview,
+            ApiVersion::V2025_05_01Preview,
+            ApiVersion::V2025_08_01Preview,
+            ApiVersion::V2025_09_01,
+        ] {
+            assert_eq!(ApiVersion::from(version.to_string()), version);
+        }
+        assert_eq!(ApiVersion::default().as_str(), "2024-07-01");
+    }
+
+    #[test]
+    fn unknown_versions_pass_through() {
+        let version = ApiVersion::from("2026-01-01-preview");
+        assert_eq!(
+            version,
+            ApiVersion::Custom("2026-01-01-preview".to_string())
+        );
+        assert_eq!(version.as_str(), "2026-01-01-preview");
+        assert!(version.is_preview());
+        assert!(!ApiVersion::from(&"2026-01-01".to_string()).is_preview());
+        assert!(ApiVersion::V2025_08_01Preview.is_preview());
+    }
+}
@@ -1,7 +1,17 @@
-use reqwest::{Client, Url};
+use std::time::Duration;
+
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue};
+use reqwest::{Client, Proxy, StatusCode, Url};
 use serde::de::DeserializeOwned;
 
-use crate::error::Result;
+use crate::api_version::ApiVersion;
+use crate::credential::Credential;
+use crate::error::{Error, ErrorDetail, Result};
+use crate::models::search::CoveragePolicy;
+use crate::retry::RetryPolicy;
+
+/// Default user agent sent with every request
+const USER_AGENT: &str = concat!("azure-search-rs/", env!("CARGO_PKG_VERSION"));
 
 /// Main client for interacting with Azure AI Search
 #[derive(Debug, Clone)]
@@ -9,57 +19,528 @@ pub struct AzureSearchClient {
     /// Base URL for the search service (e.g., https://myservice.search.windows.net)
     endpoint: Url,
     /// API version to use (e.g., "2023-11-01")
-    api_version: String,
-    /// Admin API key for authentication
-    api_key: String,
+    api_version: ApiVersion,
+    /// Credential used to authenticate requests
+    credential: Credential,
     /// Reusable HTTP client
     http_client: Client,
+    /// Retry policy for transient failures
+    retry_policy: RetryPolicy,
+    /// Handling of search responses below `minimum_coverage`
+    coverage_policy: CoveragePolicy,
 }
 
 impl AzureSearchClient {
-    /// Create a new AzureSearchClient
+    /// Create a new AzureSearchClient authenticated with an API key
     pub fn new(
         endpoint: impl Into<String>,
         api_key: impl Into<String>,
-        api_version: impl Into<String>,
+        api_version: impl Into<ApiVersion>,
+        http_client: Option<Client>,
+    ) -> Result<Self> {
+        Self::new_with_credential(
+            endpoint,
+            Credential::api_key(api_key),
+            api_version,
+            http_client,
+        )
+    }
+
+    /// Create a new AzureSearchClient with an API key or token credential
+    pub fn new_with_credential(
+        endpoint: impl Into<String>,
+        credential: impl Into<Credential>,
+        api_version: impl Into<ApiVersion>,
         http_client: Option<Client>,
     ) -> Result<Self> {
         let endpoint = Url::parse(&endpoint.into())?;
         Ok(Self {
             endpoint,
             api_version: api_version.into(),
-            api_key: api_key.into(),
-            http_client: http_client.unwrap_or_else(Client::new),
+            credential: credential.into(),
+            http_client: http_client.unwrap_or_default(),
+            retry_policy: RetryPolicy::default(),
+            coverage_policy: CoveragePolicy::default(),
         })
     }
 
+    /// Create a builder for configuring a client
+    pub fn builder() -> AzureSearchClientBuilder {
+        AzureSearchClientBuilder::new()
+    }
+
+    /// Base URL of the search service
+    pub fn endpoint(&self) -> &Url {
+        &self.endpoint
+    }
+
+    /// API version sent with every request
+    pub fn api_version(&self) -> &ApiVersion {
+        &self.api_version
+    }
+
+    /// Replace the retry policy used for every request
+    pub fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
+    }
+
+    /// Retry policy used for every request
+    pub fn retry_policy(&self) -> &RetryPolicy {
+        &self.retry_policy
+    }
+
+    /// Replace the handling of search responses below `minimum_coverage`
+    pub fn with_coverage_policy(mut self, coverage_policy: CoveragePolicy) -> Self {
+        self.coverage_policy = coverage_policy;
+        self
+    }
+
+    /// Handling of search responses below `minimum_coverage`
+    pub fn coverage_policy(&self) -> CoveragePolicy {
+        self.coverage_policy
+    }
+
     /// Helper method for making authenticated requests
+    ///
+    /// Throttled, unavailable and transient connection failures are retried
+    /// according to the client's [`RetryPolicy`] when the request is safe to resend.
+    /// A request rejected with 401 is resent once with a freshly acquired token.
     pub async fn send_request<T: DeserializeOwned, B: serde::Serialize>(
         &self,
         method: reqwest::Method,
         path: &str,
         body: Option<&B>,
+    ) -> Result<T> {
+        self.send_request_with_query(method, path, &[], body).await
+    }
+
+    /// Like [`AzureSearchClient::send_request`], adding query parameters next
+    /// to `api-version`
+    pub async fn send_request_with_query<T: DeserializeOwned, B: serde::Serialize>(
+        &self,
+        method: reqwest::Method,
+        path: &str,
+        query: &[(&str, &str)],
+        body: Option<&B>,
     ) -> Result<T> {
         let url = self.endpoint.join(path)?;
-        let mut request = self
-            .http_client
-            .request(method, url)
-            .header("api-key", &self.api_key)
-            .header("Content-Type", "application/json")
-            .query(&[("api-version", &self.api_version)]);
-
-        if let Some(body) = body {
-            request = request.json(body);
+        let max_attempts = if RetryPolicy::is_retry_safe(&method, path) {
+            self.retry_policy.max_attempts.max(1)
+        } else {
+            1
+        };
+
+        let mut attempt = 1;
+        let mut reauthenticated = false;
+        loop {
+            let (auth_name, auth_value) = self.credential.header().await?;
+            let mut request = self
+                .http_client
+                .request(method.clone(), url.clone())
+                .header(auth_name, auth_value)
+                .header("Content-Type", "application/json")
+                .query(&[("api-version", self.api_version.as_str())])
+                .query(query);
+
+            if let Some(body) = body {
+                request = request.json(body);
+            }
+
+            let response = match request.send().await {
+                Ok(response) => response,
+                Err(err) if attempt < max_attempts && RetryPolicy::is_retriable_error(&err) => {
+                    tokio::time::sleep(self.retry_policy.delay_for(attempt, None)).await;
+                    attempt += 1;
+                    continue;
+                }
+                Err(err) => return Err(err.into()),
+            };
+
+            let status = response.status();
+            // The cached token may have been revoked before its expiry
+            if status == StatusCode::UNAUTHORIZED
+                && !reauthenticated
+                && self.credential.invalidate().await
+            {
+                reauthenticated = true;
+                continue;
+            }
+            if attempt < max_attempts && RetryPolicy::is_retriable_status(status) {
+                let delay = self
+                    .retry_policy
+                    .delay_for(attempt, Some(response.headers()));
+                tokio::time::sleep(delay).await;
+                attempt += 1;
+                continue;
+            }
+
+            let request_id = ["request-id", "x-ms-request-id"].iter().find_map(|name| {
+                response
+                    .headers()
+                    .get(*name)
+                    .and_then(|value| value.to_str().ok())
+                    .map(str::to_string)
+            });
+            let body = response.text().await?;
+
+            if !status.is_success() {
+                return Err(Error::RequestFailed {
+                    status,
+                    error: ErrorDetail::from_body(&body).map(Box::new),
+                    body,
+                    request_id,
+                });
+            }
+
+            // Responses without content (e.g. 204) deserialize as `null`
+            let body = if body.is_empty() { "null" } else { &body };
+            return serde_json::from_str(body).map_err(Into::into);
         }
+    }
+}
 
-        let response = request.send().await?;
-        let status = response.status();
-        let body = response.text().await?;
+/// Builder for [`AzureSearchClient`]
+#[derive(Debug, Default)]
+pub struct AzureSearchClientBuilder {
+    endpoint: Option<String>,
+    credential: Option<Credential>,
+    api_version: ApiVersion,
+    http_client: Option<Client>,
+    connect_timeout: Option<Duration>,
+    timeout: Option<Duration>,
+    user_agent_suffix: Option<String>,
+    default_headers: Vec<(String, String)>,
+    proxy: Option<Proxy>,
+    retry_policy: RetryPolicy,
+    coverage_policy: CoveragePolicy,
+}
+
+impl AzureSearchClientBuilder {
+    /// Create an empty builder
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Configure from environment variables
+    ///
+    /// Reads `AZURE_SEARCH_ENDPOINT` (or `AZURE_SEARCH_SERVICE_NAME`),
+    /// `AZURE_SEARCH_API_KEY` and the optional `AZURE_SEARCH_API_VERSION`.
+    pub fn from_env() -> Result<Self> {
+        let var = |name: &str| std::env::var(name).ok().filter(|value| !value.is_empty());
+
+        let endpoint = match var("AZURE_SEARCH_ENDPOINT") {
+            Some(endpoint) => endpoint,
+            None => match var("AZURE_SEARCH_SERVICE_NAME") {
+                Some(service_name) => service_endpoint(&service_name),
+                None => {
+                    return Err(Error::Configuration(
+                        "AZURE_SEARCH_ENDPOINT or AZURE_SEARCH_SERVICE_NAME must be set"
+                            .to_string(),
+                    ))
+                }
+            },
+        };
+
+        let mut builder = Self::new().endpoint(endpoint);
+        if let Some(api_key) = var("AZURE_SEARCH_API_KEY") {
+            builder = builder.api_key(api_key);
+        }
+        if let Some(api_version) = var("AZURE_SEARCH_API_VERSION") {
+            builder = builder.api_version(api_version);
+        }
+        Ok(builder)
+    }
 
-        if !status.is_success() {
-            return Err(crate::error::Error::RequestFailed { status, body });
+    /// Configure from a connection string
+    ///
+    /// The string is a `;`-separated list of `Key=Value` pairs with the keys
+    /// `Endpoint` (or `ServiceName`), `ApiKey` and `ApiVersion`, e.g.
+    /// `Endpoint=https://myservice.search.windows.net;ApiKey=...;ApiVersion=2024-07-01`.
+    pub fn from_connection_string(connection_string: &str) -> Result<Self> {
+        let mut builder = Self::new();
+        for part in connection_string.split(';').map(str::trim) {
+            if part.is_empty() {
+                continue;
+            }
+            let (key, value) = part.split_once('=').ok_or_else(|| {
+                Error::Configuration(format!("invalid connection string segment: {}", part))
+            })?;
+            let value = value.trim();
+            match key.trim().to_ascii_lowercase().as_str() {
+                "endpoint" => builder = builder.endpoint(value),
+                "servicename" => builder = builder.endpoint(service_endpoint(value)),
+                "apikey" => builder = builder.api_key(value),
+                "apiversion" => builder = builder.api_version(value),
+                other => {
+                    return Err(Error::Configuration(format!(
+                        "unknown connection string key: {}",
+                        other
+                    )))
+                }
+            }
         }
+        Ok(builder)
+    }
+
+    /// Set the service endpoint (e.g., https://myservice.search.windows.net)
+    pub fn endpoint(mut self, endpoint: impl Into<String>) -> Self {
+        self.endpoint = Some(endpoint.into());
+        self
+    }
+
+    /// Authenticate with an admin or query key
+    pub fn api_key(mut self, api_key: impl Into<String>) -> Self {
+        self.credential = Some(Credential::api_key(api_key));
+        self
+    }
+
+    /// Authenticate with an API key or token credential
+    pub fn credential(mut self, credential: impl Into<Credential>) -> Self {
+        self.credential = Some(credential.into());
+        self
+    }
+
+    /// Set the API version (defaults to [`ApiVersion::V2024_07_01`])
+    pub fn api_version(mut self, api_version: impl Into<ApiVersion>) -> Self {
+        self.api_version = api_version.into();
+        self
+    }
+
+    /// Use a preconfigured HTTP client
+    ///
+    /// Can't be combined with timeouts, a user agent suffix, default headers
+    /// or a proxy, which must be configured on the client itself.
+    pub fn http_client(mut self, http_client: Client) -> Self {
+        self.http_client = Some(http_client);
+        self
+    }
+
+    /// Timeout for establishing a connection
+    pub fn connect_timeout(mut self, timeout: Duration) -> Self {
+        self.connect_timeout = Some(timeout);
+        self
+    }
+
+    /// Timeout for a whole request, including reading the response
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Append a suffix to the default user agent
+    pub fn user_agent_suffix(mut self, suffix: impl Into<String>) -> Self {
+        self.user_agent_suffix = Some(suffix.into());
+        self
+    }
+
+    /// Add a header sent with every request
+    pub fn default_header(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.default_headers.push((name.into(), value.into()));
+        self
+    }
+
+    /// Route requests through a proxy
+    pub fn proxy(mut self, proxy: Proxy) -> Self {
+        self.proxy = Some(proxy);
+        self
+    }
+
+    /// Set the retry policy
+    pub fn retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
+    }
+
+    /// Set the handling of search responses below `minimum_coverage`
+    pub fn coverage_policy(mut self, coverage_policy: CoveragePolicy) -> Self {
+        self.coverage_policy = coverage_policy;
+        self
+    }
+
+    /// Build the client
+    pub fn build(self) -> Result<AzureSearchClient> {
+        let endpoint = self
+            .endpoint
+            .ok_or_else(|| Error::Configuration("endpoint is required".to_string()))?;
+        let credential = self.credential.ok_or_else(|| {
+            Error::Configuration("an API key or credential is required".to_string())
+        })?;
+
+        let http_client = match self.http_client {
+            Some(http_client) => {
+                let conflicting = [
+                    ("connect_timeout", self.connect_timeout.is_some()),
+                    ("timeout", self.timeout.is_some()),
+                    ("user_agent_suffix", self.user_agent_suffix.is_some()),
+                    ("default_header", !self.default_headers.is_empty()),
+                    ("proxy", self.proxy.is_some()),
+                ];
+                let conflicting: Vec<&str> = conflicting
+                    .iter()
+                    .filter(|(_, set)| *set)
+                    .map(|(name, _)| *name)
+                    .collect();
+                if !conflicting.is_empty() {
+                    return Err(Error::Configuration(format!(
+                        "http_client can't be combined with {}; configure them on the HTTP client",
+                        conflicting.join(", ")
+                    )));
+                }
+                http_client
+            }
+            None => {
+                let mut headers = HeaderMap::new();
+                for (name, value) in &self.default_headers {
+                    let name = HeaderName::from_bytes(name.as_bytes()).map_err(|err| {
+                        Error::Configuration(format!("invalid header name {}: {}", name, err))
+                    })?;
+                    let value = HeaderValue::from_str(value).map_err(|err| {
+                        Error::Configuration(format!("invalid header value for {}: {}", name, err))
+                    })?;
+                    headers.append(name, value);
+                }
+
+                let user_agent = match &self.user_agent_suffix {
+                    Some(suffix) => format!("{} {}", USER_AGENT, suffix),
+                    None => USER_AGENT.to_string(),
+                };
+
+                let mut builder = Client::builder()
+                    .user_agent(user_agent)
+                    .default_headers(headers);
+                if let Some(timeout) = self.connect_timeout {
+                    builder = builder.connect_timeout(timeout);
+                }
+                if let Some(timeout) = self.timeout {
+                    builder = builder.timeout(timeout);
+                }
+                if let Some(proxy) = self.proxy {
+                    builder = builder.proxy(proxy);
+                }
+                builder.build()?
+            }
+        };
+
+        Ok(AzureSearchClient::new_with_credential(
+            endpoint,
+            credential,
+            self.api_version,
+            Some(http_client),
+        )?
+        .with_retry_policy(self.retry_policy)
+        .with_coverage_policy(self.coverage_policy))
+    }
+}
+
+/// Endpoint URL for a service name in the public cloud
+fn service_endpoint(service_name: &str) -> String {
+    format!("https://{}.search.windows.net", service_name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn configuration_error(result: Result<impl std::fmt::Debug>) -> String {
+        match result.unwrap_err() {
+            Error::Configuration(message) => message,
+            other => panic!("unexpected error: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn connection_string() {
+        let client = AzureSearchClientBuilder::from_connection_string(
+            "Endpoint=https://hotels.search.windows.net; ApiKey=secret==;apiversion=2023-11-01;",
+        )
+        .unwrap()
+        .build()
+        .unwrap();
+        assert_eq!(
+            client.endpoint().as_str(),
+            "https://hotels.search.windows.net/"
+        );
+        assert_eq!(client.api_version(), &ApiVersion::V2023_11_01);
+        assert!(matches!(&client.credential, Credential::ApiKey(key) if key == "secret=="));
+    }
+
+    #[test]
+    fn connection_string_with_service_name() {
+        let client =
+            AzureSearchClientBuilder::from_connection_string("ServiceName=hotels;ApiKey=secret")
+                .unwrap()
+                .build()
+                .unwrap();
+        assert_eq!(
+            client.endpoint().as_str(),
+            "https://hotels.search.windows.net/"
+        );
+        assert_eq!(client.api_version(), &ApiVersion::default());
+    }
+
+    #[test]
+    fn malformed_connection_string() {
+        let message = configuration_error(AzureSearchClientBuilder::from_connection_string(
+            "Endpoint=https://hotels.search.windows.net;secret",
+        ));
+        assert!(
+            message.contains("invalid connection string segment"),
+            "{}",
+            message
+        );
+
+        let message = configuration_error(AzureSearchClientBuilder::from_connection_string(
+            "Endpoint=https://hotels.search.windows.net;Key=secret",
+        ));
+        assert!(
+            message.contains("unknown connection string key: key"),
+            "{}",
+            message
+        );
+
+        let message =
+            configuration_error(AzureSearchClientBuilder::from_connection_string("=secret"));
+        assert!(
+            message.contains("unknown connection string key"),
+            "{}",
+            message
+        );
+    }
+
+    #[test]
+    fn connection_string_without_api_key() {
+        let builder = AzureSearchClientBuilder::from_connection_string(
+            "Endpoint=https://hotels.search.windows.net",
+        )
+        .unwrap();
+        let message = configuration_error(builder.build());
+        assert!(message.contains("API key or credential"), "{}", message);
+
+        let builder = AzureSearchClientBuilder::from_connection_string("ApiKey=secret").unwrap();
+        let message = configuration_error(builder.build());
+        assert!(message.contains("endpoint"), "{}", message);
+    }
+
+    #[test]
+    fn http_client_conflicts_with_client_options() {
+        let builder = || {
+            AzureSearchClientBuilder::new()
+                .endpoint("https://hotels.search.windows.net")
+                .api_key("secret")
+                .http_client(Client::new())
+        };
+        assert!(builder().build().is_ok());
+
+        let message = configuration_error(
+            builder()
+                .timeout(Duration::from_secs(5))
+                .default_header("x-ms-client-request-id", "1")
+                .build(),
+        );
+        assert!(message.contains("timeout, default_header"), "{}", message);
 
-        serde_json::from_str(&body).map_err(Into::into)
+        let message = configuration_error(builder().user_agent_suffix("app").build());
+        assert!(message.contains("user_agent_suffix"), "{}", message);
     }
 }
@@ -0,0 +1,650 @@
+//! Authentication credentials for Azure AI Search
+//!
+//! Requests are authenticated either with an `api-key` header or with a
+//! Microsoft Entra ID bearer token obtained from a [`TokenCredential`].
+
+use std::fmt;
+use std::future::Future;
+use std::path::PathBuf;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::time::{Duration, SystemTime};
+
+use reqwest::Client;
+use serde::Deserialize;
+use tokio::sync::Mutex;
+
+use crate::error::{Error, Result};
+
+/// OAuth scope for the Azure AI Search data plane
+pub const SEARCH_SCOPE: &str = "https://search.azure.com/.default";
+
+/// Default Microsoft Entra ID authority
+pub const DEFAULT_AUTHORITY_HOST: &str = "https://login.microsoftonline.com";
+
+/// Boxed future returned by [`TokenCredential::get_token`]
+pub type TokenFuture<'a> = Pin<Box<dyn Future<Output = Result<AccessToken>> + Send + 'a>>;
+
+/// Bearer token with its expiry
+#[derive(Clone)]
+pub struct AccessToken {
+    /// Token value sent as `Authorization: Bearer <token>`
+    pub token: String,
+    /// When the token expires; `None` means it never does
+    pub expires_on: Option<SystemTime>,
+}
+
+impl AccessToken {
+    /// Create a new access token
+    pub fn new(token: impl Into<String>, expires_on: Option<SystemTime>) -> Self {
+        Self {
+            token: token.into(),
+            expires_on,
+        }
+    }
+
+    /// Whether the token expires within `margin` from now
+    pub fn expires_within(&self, margin: Duration) -> bool {
+        match self.expires_on {
+            Some(expires_on) => SystemTime::now() + margin >= expires_on,
+            None => false,
+        }
+    }
+}
+
+impl fmt::Debug for AccessToken {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("AccessToken")
+            .field("token", &"<redacted>")
+            .field("expires_on", &self.expires_on)
+            .finish()
+    }
+}
+
+/// Source of Microsoft Entra ID bearer tokens
+pub trait TokenCredential: Send + Sync {
+    /// Acquire a token for the given scopes
+    fn get_token<'a>(&'a self, scopes: &'a [&'a str]) -> TokenFuture<'a>;
+}
+
+/// Credential used by [`crate::AzureSearchClient`] to authenticate requests
+#[derive(Clone)]
+pub enum Credential {
+    /// Admin or query key sent in the `api-key` header
+    ApiKey(String),
+    /// Bearer token provider, cached and refreshed before expiry
+    Token(Arc<TokenCache>),
+}
+
+impl Credential {
+    /// Authenticate with an admin or query key
+    pub fn api_key(key: impl Into<String>) -> Self {
+        Credential::ApiKey(key.into())
+    }
+
+    /// Authenticate with bearer tokens from the given provider
+    ///
+    /// Tokens are requested for [`SEARCH_SCOPE`] and refreshed five minutes
+    /// before expiry. To change either, configure a [`TokenCache`] and pass it
+    /// instead of the provider.
+    pub fn token(credential: impl TokenCredential + 'static) -> Self {
+        Credential::Token(Arc::new(TokenCache::new(Arc::new(credential))))
+    }
+
+    /// Header name and value to attach to a request
+    pub(crate) async fn header(&self) -> Result<(&'static str, String)> {
+        match self {
+            Credential::ApiKey(key) => Ok(("api-key", key.clone())),
+            Credential::Token(cache) => {
+                let token = cache.token().await?;
+                Ok(("Authorization", format!("Bearer {}", token.token)))
+            }
+        }
+    }
+
+    /// Drop a cached token rejected by the service; `false` for API keys,
+    /// which can't be refreshed
+    pub(crate) async fn invalidate(&self) -> bool {
+        match self {
+            Credential::ApiKey(_) => false,
+            Credential::Token(cache) => {
+                cache.invalidate().await;
+                true
+            }
+        }
+    }
+}
+
+impl fmt::Debug for Credential {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Credential::ApiKey(_) => f.write_str("ApiKey(<redacted>)"),
+            Credential::Token(_) => f.write_str("Token(..)"),
+        }
+    }
+}
+
+impl<C: TokenCredential + 'static> From<C> for Credential {
+    fn from(credential: C) -> Self {
+        Credential::token(credential)
+    }
+}
+
+impl From<TokenCache> for Credential {
+    fn from(cache: TokenCache) -> Self {
+        Credential::Token(Arc::new(cache))
+    }
+}
+
+impl From<Arc<TokenCache>> for Credential {
+    fn from(cache: Arc<TokenCache>) -> Self {
+        Credential::Token(cache)
+    }
+}
+
+/// Caches tokens from a [`TokenCredential`] and refreshes them before expiry
+///
+/// Converts into a [`Credential`], so a cache configured with
+/// [`TokenCache::with_scopes`] or [`TokenCache::with_refresh_margin`] can be
+/// passed wherever a credential is accepted. A shared `Arc<TokenCache>` lets
+/// several clients reuse the same token.
+pub struct TokenCache {
+    credential: Arc<dyn TokenCredential>,
+    scopes: Vec<String>,
+    refresh_margin: Duration,
+    /// Cached token and when it was acquired
+    cached: Mutex<Option<(AccessToken, SystemTime)>>,
+}
+
+impl TokenCache {
+    /// Cache tokens for the search scope, refreshing five minutes before expiry
+    ///
+    /// Tokens that live shorter than twice the margin are refreshed halfway
+    /// through their lifetime instead.
+    pub fn new(credential: Arc<dyn TokenCredential>) -> Self {
+        Self {
+            credential,
+            scopes: vec![SEARCH_SCOPE.to_string()],
+            refresh_margin: Duration::from_secs(300),
+            cached: Mutex::new(None),
+        }
+    }
+
+    /// Request tokens for different scopes
+    pub fn with_scopes(mut self, scopes: Vec<String>) -> Self {
+        self.scopes = scopes;
+        self
+    }
+
+    /// Change how long before expiry a token is refreshed
+    pub fn with_refresh_margin(mut self, refresh_margin: Duration) -> Self {
+        self.refresh_margin = refresh_margin;
+        self
+    }
+
+    /// Return the cached token, acquiring a new one if missing or about to expire
+    pub async fn token(&self) -> Result<AccessToken> {
+        let mut cached = self.cached.lock().await;
+        if let Some((token, acquired_on)) = cached.as_ref() {
+            if !token.expires_within(self.margin_for(token, *acquired_on)) {
+                return Ok(token.clone());
+            }
+        }
+
+        let scopes: Vec<&str> = self.scopes.iter().map(String::as_str).collect();
+        let acquired_on = SystemTime::now();
+        let token = self.credential.get_token(&scopes).await?;
+        *cached = Some((token.clone(), acquired_on));
+        Ok(token)
+    }
+
+    /// Refresh margin for a token, at most half its lifetime
+    fn margin_for(&self, token: &AccessToken, acquired_on: SystemTime) -> Duration {
+        let lifetime = token
+            .expires_on
+            .and_then(|expires_on| expires_on.duration_since(acquired_on).ok())
+            .unwrap_or_default();
+        self.refresh_margin.min(lifetime / 2)
+    }
+
+    /// Drop the cached token so the next request acquires a fresh one
+    pub async fn invalidate(&self) {
+        *self.cached.lock().await = None;
+    }
+}
+
+/// A fixed bearer token, e.g. one issued by an external process
+#[derive(Clone)]
+pub struct StaticTokenCredential {
+    token: AccessToken,
+}
+
+impl StaticTokenCredential {
+    /// Create a credential that always returns the given token
+    pub fn new(token: impl Into<String>) -> Self {
+        Self {
+            token: AccessToken::new(token, None),
+        }
+    }
+}
+
+impl TokenCredential for StaticTokenCredential {
+    fn get_token<'a>(&'a self, _scopes: &'a [&'a str]) -> TokenFuture<'a> {
+        Box::pin(async move { Ok(self.token.clone()) })
+    }
+}
+
+/// Token provider backed by an async closure
+pub struct ClosureCredential<F> {
+    provider: F,
+}
+
+impl<F, Fut> ClosureCredential<F>
+where
+    F: Fn(Vec<String>) -> Fut + Send + Sync,
+    Fut: Future<Output = Result<AccessToken>> + Send + 'static,
+{
+    /// Create a credential that calls `provider` with the requested scopes
+    pub fn new(provider: F) -> Self {
+        Self { provider }
+    }
+}
+
+impl<F, Fut> TokenCredential for ClosureCredential<F>
+where
+    F: Fn(Vec<String>) -> Fut + Send + Sync,
+    Fut: Future<Output = Result<AccessToken>> + Send + 'static,
+{
+    fn get_token<'a>(&'a self, scopes: &'a [&'a str]) -> TokenFuture<'a> {
+        let scopes = scopes.iter().map(|scope| scope.to_string()).collect();
+        Box::pin((self.provider)(scopes))
+    }
+}
+
+/// Service principal authentication with a client secret
+#[derive(Clone)]
+pub struct ClientSecretCredential {
+    tenant_id: String,
+    client_id: String,
+    client_secret: String,
+    authority_host: String,
+    http_client: Client,
+}
+
+impl ClientSecretCredential {
+    /// Create a new client secret credential
+    pub fn new(
+        tenant_id: impl Into<String>,
+        client_id: impl Into<String>,
+        client_secret: impl Into<String>,
+    ) -> Self {
+        Self {
+            tenant_id: tenant_id.into(),
+            client_id: client_id.into(),
+            client_secret: client_secret.into(),
+            authority_host: DEFAULT_AUTHORITY_HOST.to_string(),
+            http_client: Client::new(),
+        }
+    }
+
+    /// Use a different authority host (sovereign clouds or a local stand-in)
+    pub fn with_authority_host(mut self, authority_host: impl Into<String>) -> Self {
+        self.authority_host = authority_host.into();
+        self
+    }
+
+    /// Use a custom HTTP client for token requests
+    pub fn with_http_client(mut self, http_client: Client) -> Self {
+        self.http_client = http_client;
+        self
+    }
+}
+
+impl fmt::Debug for ClientSecretCredential {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ClientSecretCredential")
+            .field("tenant_id", &self.tenant_id)
+            .field("client_id", &self.client_id)
+            .field("client_secret", &"<redacted>")
+            .field("authority_host", &self.authority_host)
+            .finish()
+    }
+}
+
+impl TokenCredential for ClientSecretCredential {
+    fn get_token<'a>(&'a self, scopes: &'a [&'a str]) -> TokenFuture<'a> {
+        Box::pin(async move {
+            let scope = scopes.join(" ");
+            let form = [
+                ("grant_type", "client_credentials"),
+                ("client_id", self.client_id.as_str()),
+                ("client_secret", self.client_secret.as_str()),
+                ("scope", scope.as_str()),
+            ];
+            request_token(
+                &self.http_client,
+                &self.authority_host,
+                &self.tenant_id,
+                &form,
+            )
+            .await
+        })
+    }
+}
+
+/// Workload identity federation, e.g. on AKS with a projected service account token
+#[derive(Debug, Clone)]
+pub struct WorkloadIdentityCredential {
+    tenant_id: String,
+    client_id: String,
+    token_file_path: PathBuf,
+    authority_host: String,
+    http_client: Client,
+}
+
+impl WorkloadIdentityCredential {
+    /// Create a new workload identity credential
+    pub fn new(
+        tenant_id: impl Into<String>,
+        client_id: impl Into<String>,
+        token_file_path: impl Into<PathBuf>,
+    ) -> Self {
+        Self {
+            tenant_id: tenant_id.into(),
+            client_id: client_id.into(),
+            token_file_path: token_file_path.into(),
+            authority_host: DEFAULT_AUTHORITY_HOST.to_string(),
+            http_client: Client::new(),
+        }
+    }
+
+    /// Create from `AZURE_TENANT_ID`, `AZURE_CLIENT_ID`, `AZURE_FEDERATED_TOKEN_FILE`
+    /// and the optional `AZURE_AUTHORITY_HOST`
+    pub fn from_env() -> Result<Self> {
+        let var = |name: &str| {
+            std::env::var(name).map_err(|_| Error::Authentication(format!("{} is not set", name)))
+        };
+        let credential = Self::new(
+            var("AZURE_TENANT_ID")?,
+            var("AZURE_CLIENT_ID")?,
+            var("AZURE_FEDERATED_TOKEN_FILE")?,
+        );
+        Ok(match std::env::var("AZURE_AUTHORITY_HOST") {
+            Ok(host) => credential.with_authority_host(host),
+            Err(_) => credential,
+        })
+    }
+
+    /// Use a different authority host (sovereign clouds or a local stand-in)
+    pub fn with_authority_host(mut self, authority_host: impl Into<String>) -> Self {
+        self.authority_host = authority_host.into();
+        self
+    }
+
+    /// Use a custom HTTP client for token requests
+    pub fn with_http_client(mut self, http_client: Client) -> Self {
+        self.http_client = http_client;
+        self
+    }
+}
+
+impl TokenCredential for WorkloadIdentityCredential {
+    fn get_token<'a>(&'a self, scopes: &'a [&'a str]) -> TokenFuture<'a> {
+        Box::pin(async move {
+            // The projected token is rotated on disk, so read it on every exchange
+            let assertion = tokio::fs::read_to_string(&self.token_file_path)
+                .await
+                .map_err(|err| {
+                    Error::Authentication(format!(
+                        "failed to read federated token file {}: {}",
+                        self.token_file_path.display(),
+                        err
+                    ))
+                })?;
+            let scope = scopes.join(" ");
+            let form = [
+                ("grant_type", "client_credentials"),
+                ("client_id", self.client_id.as_str()),
+                (
+                    "client_assertion_type",
+                    "urn:ietf:params:oauth:client-assertion-type:jwt-bearer",
+                ),
+                ("client_assertion", assertion.trim()),
+                ("scope", scope.as_str()),
+            ];
+            request_token(
+                &self.http_client,
+                &self.authority_host,
+                &self.tenant_id,
+                &form,
+            )
+            .await
+        })
+    }
+}
+
+/// Token endpoint response
+#[derive(Deserialize)]
+struct TokenResponse {
+    access_token: String,
+    expires_in: u64,
+}
+
+/// Exchange form parameters for a token at `{authority}/{tenant}/oauth2/v2.0/token`
+async fn request_token(
+    http_client: &Client,
+    authority_host: &str,
+    tenant_id: &str,
+    form: &[(&str, &str)],
+) -> Result<AccessToken> {
+    let url = format!(
+        "{}/{}/oauth2/v2.0/token",
+        authority_host.trim_end_matches('/'),
+        tenant_id
+    );
+    let response = http_client.post(url).form(form).send().await?;
+    let status = response.status();
+    let body = response.text().await?;
+
+    if !status.is_success() {
+        return Err(Error::Authentication(format!(
+            "token request failed with status {}: {}",
+            status, body
+        )));
+    }
+
+    let token: TokenResponse = serde_json::from_str(&body)?;
+    Ok(AccessToken::new(
+        token.access_token,
+        Some(SystemTime::now() + Duration::from_secs(token.expires_in)),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use super::*;
+    use crate::test_util::{TestResponse, TestServer};
+
+    /// Token endpoint issuing `token-1`, `token-2`, ... valid for `expires_in` seconds
+    async fn token_endpoint(expires_in: u64) -> TestServer {
+        let issued = AtomicUsize::new(0);
+        TestServer::start(move |_| {
+            let token = issued.fetch_add(1, Ordering::SeqCst) + 1;
+            TestResponse::json(
+                200,
+                serde_json::json!({
+                    "token_type": "Bearer",
+                    "access_token": format!("token-{}", token),
+                    "expires_in": expires_in,
+                }),
+            )
+        })
+        .await
+    }
+
+    fn form(body: &str) -> Vec<(String, String)> {
+        url::form_urlencoded::parse(body.as_bytes())
+            .into_owned()
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn client_secret_token_is_cached() {
+        let server = token_endpoint(3600).await;
+        let credential = ClientSecretCredential::new("tenant", "client", "secret")
+            .with_authority_host(&server.url);
+        let cache = TokenCache::new(Arc::new(credential));
+
+        assert_eq!(cache.token().await.unwrap().token, "token-1");
+        assert_eq!(cache.token().await.unwrap().token, "token-1");
+        assert_eq!(server.request_count(), 1);
+
+        let request = &server.requests()[0];
+        assert_eq!(request.method, "POST");
+        assert_eq!(request.path, "/tenant/oauth2/v2.0/token");
+        let form = form(&request.body);
+        assert!(form.contains(&("client_secret".to_string(), "secret".to_string())));
+        assert!(form.contains(&("scope".to_string(), SEARCH_SCOPE.to_string())));
+    }
+
+    #[tokio::test]
+    async fn short_lived_token_is_refreshed_at_half_lifetime() {
+        let server = token_endpoint(2).await;
+        let credential = ClientSecretCredential::new("tenant", "client", "secret")
+            .with_authority_host(&server.url);
+        let cache = TokenCache::new(Arc::new(credential));
+
+        // A fixed 5 minute margin would refetch a 2 second token on every call
+        assert_eq!(cache.token().await.unwrap().token, "token-1");
+        assert_eq!(cache.token().await.unwrap().token, "token-1");
+        assert_eq!(server.request_count(), 1);
+
+        tokio::time::sleep(Duration::from_millis(1100)).await;
+        assert_eq!(cache.token().await.unwrap().token, "token-2");
+        assert_eq!(server.request_count(), 2);
+    }
+
+    #[tokio::test]
+    async fn invalidated_token_is_refetched() {
+        let server = token_endpoint(3600).await;
+        let credential = ClientSecretCredential::new("tenant", "client", "secret")
+            .with_authority_host(&server.url);
+        let cache = TokenCache::new(Arc::new(credential));
+
+        assert_eq!(cache.token().await.unwrap().token, "token-1");
+        cache.invalidate().await;
+        assert_eq!(cache.token().await.unwrap().token, "token-2");
+    }
+
+    #[tokio::test]
+    async fn workload_identity_exchanges_federated_token() {
+        let server = token_endpoint(3600).await;
+        let token_file = std::env::temp_dir().join(format!(
+            "azure-search-rs-federated-token-{}",
+            std::process::id()
+        ));
+        tokio::fs::write(&token_file, "federated-1\n")
+            .await
+            .unwrap();
+
+        let credential = WorkloadIdentityCredential::new("tenant", "client", &token_file)
+            .with_authority_host(&server.url);
+        let cache = TokenCache::new(Arc::new(credential));
+        assert_eq!(cache.token().await.unwrap().token, "token-1");
+        assert_eq!(cache.token().await.unwrap().token, "token-1");
+
+        // The rotated assertion is read again on the next exchange
+        tokio::fs::write(&token_file, "federated-2").await.unwrap();
+        cache.invalidate().await;
+        assert_eq!(cache.token().await.unwrap().token, "token-2");
+        tokio::fs::remove_file(&token_file).await.unwrap();
+
+        let assertions: Vec<String> = server
+            .requests()
+            .iter()
+            .map(|request| {
+                form(&request.body)
+                    .into_iter()
+                    .find(|(name, _)| name == "client_assertion")
+                    .unwrap()
+                    .1
+            })
+            .collect();
+        assert_eq!(assertions, ["federated-1", "federated-2"]);
+    }
+
+    #[tokio::test]
+    async fn token_endpoint_errors_are_reported() {
+        let server = TestServer::start(|_| {
+            TestResponse::json(400, serde_json::json!({"error": "invalid_client"}))
+        })
+        .await;
+        let credential = ClientSecretCredential::new("tenant", "client", "wrong")
+            .with_authority_host(&server.url);
+
+        let err = credential.get_token(&[SEARCH_SCOPE]).await.unwrap_err();
+        assert!(
+            matches!(err, Error::Authentication(message) if message.contains("invalid_client"))
+        );
+    }
+
+    #[tokio::test]
+    async fn client_refreshes_token_rejected_with_401() {
+        let token_server = token_endpoint(3600).await;
+        let search_server = TestServer::start(|request| match request.header("authorization") {
+            Some("Bearer token-1") => TestResponse::new(401, ""),
+            _ => TestResponse::json(200, serde_json::json!({"id": "1"})),
+        })
+        .await;
+
+        let credential = ClientSecretCredential::new("tenant", "client", "secret")
+            .with_authority_host(&token_server.url);
+        let client = crate::AzureSearchClient::new_with_credential(
+            &search_server.url,
+            credential,
+            "2024-07-01",
+            None,
+        )
+        .unwrap();
+
+        let document: serde_json::Value = client
+            .send_request::<_, ()>(reqwest::Method::POST, "indexes", None)
+            .await
+            .unwrap();
+        assert_eq!(document["id"], "1");
+        assert_eq!(token_server.request_count(), 2);
+        assert_eq!(search_server.request_count(), 2);
+    }
+
+    #[tokio::test]
+    async fn configured_cache_is_used_by_the_client() {
+        let token_server = token_endpoint(3600).await;
+        let search_server =
+            TestServer::start(|_| TestResponse::json(200, serde_json::json!({"id": "1"}))).await;
+
+        let credential = ClientSecretCredential::new("tenant", "client", "secret")
+            .with_authority_host(&token_server.url);
+        let cache = TokenCache::new(Arc::new(credential))
+            .with_scopes(vec!["api://custom/.default".to_string()]);
+        let client = crate::AzureSearchClient::new_with_credential(
+            &search_server.url,
+            cache,
+            "2024-07-01",
+            None,
+        )
+        .unwrap();
+
+        client
+            .send_request::<serde_json::Value, ()>(reqwest::Method::GET, "indexes", None)
+            .await
+            .unwrap();
+        let form = form(&token_server.requests()[0].body);
+        assert!(form.contains(&("scope".to_string(), "api://custom/.default".to_string())));
+        assert_eq!(
+            search_server.requests()[0].header("authorization"),
+            Some("Bearer token-1")
+        );
+    }
+}
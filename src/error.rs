@@ -1,12 +1,26 @@
+use std::ops::Range;
+
 use reqwest::StatusCode;
+use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
+use crate::models::document::{IndexDocumentsResult, IndexingResult};
+use crate::models::lucene::LuceneSyntaxError;
+use crate::retry::RetryPolicy;
+
 /// Main error type for Azure Search operations
 #[derive(Debug, Error)]
 pub enum Error {
     /// Failed HTTP request to Azure Search
     #[error("Request failed with status {status}: {body}")]
-    RequestFailed { status: StatusCode, body: String },
+    RequestFailed {
+        status: StatusCode,
+        body: String,
+        /// Parsed OData error payload, if the body contained one
+        error: Option<Box<ErrorDetail>>,
+        /// Value of the `request-id` / `x-ms-request-id` response header
+        request_id: Option<String>,
+    },
 
     /// Failed to parse URL
     #[error("Invalid URL: {0}")]
@@ -20,10 +34,376 @@ pub enum Error {
     #[error("HTTP client error: {0}")]
     Reqwest(#[from] reqwest::Error),
 
+    /// Some documents in an index batch failed (HTTP 207)
+    #[error("Indexing failed for {} document(s): {}", failed.len(), failed_keys(failed))]
+    IndexingFailed {
+        /// Results of the failed actions
+        failed: Vec<IndexingResult>,
+    },
+
+    /// Some requests of a split index batch failed
+    ///
+    /// Actions sent in the other requests were applied; their per-document
+    /// results are kept in `result`.
+    #[error("{} of the batch requests failed: {}", failures.len(), first_failure(failures))]
+    BatchFailed {
+        /// Results of the requests that completed
+        result: IndexDocumentsResult,
+        /// Requests that failed, in batch order
+        failures: Vec<BatchFailure>,
+    },
+
+    /// A single document exceeds the maximum batch payload size
+    #[error("Document at position {position} is {size} bytes, exceeding the batch limit of {limit} bytes")]
+    DocumentTooLarge {
+        /// Position of the action in the submitted batch
+        position: usize,
+        /// Serialized size of the action in bytes
+        size: usize,
+        /// Configured maximum batch size in bytes
+        limit: usize,
+    },
+
+    /// Query covered less of the index than `minimum_coverage`
+    #[error("Query covered {coverage}% of the index, below the minimum of {minimum_coverage}%")]
+    InsufficientCoverage {
+        /// % of the index covered by the query
+        coverage: f64,
+        /// Requested minimum %
+        minimum_coverage: u8,
+    },
+
+    /// Request failed client-side validation
+    #[error("Invalid request: {0}")]
+    Validation(#[from] ValidationError),
+
+    /// Invalid client configuration
+    #[error("Configuration error: {0}")]
+    Configuration(String),
+
+    /// Failed to acquire credentials for a request
+    #[error("Authentication error: {0}")]
+    Authentication(String),
+
     /// Azure Search specific error
     #[error("Azure Search error: {0}")]
     SearchError(String),
 }
 
+impl Error {
+    /// HTTP status of a failed request
+    pub fn status(&self) -> Option<StatusCode> {
+        match self {
+            Error::RequestFailed { status, .. } => Some(*status),
+            Error::Reqwest(err) => err.status(),
+            _ => None,
+        }
+    }
+
+    /// Parsed error payload returned by the service
+    pub fn error_detail(&self) -> Option<&ErrorDetail> {
+        match self {
+            Error::RequestFailed { error, .. } => error.as_deref(),
+            _ => None,
+        }
+    }
+
+    /// Error code returned by the service (e.g., `InvalidRequestParameter`)
+    pub fn error_code(&self) -> Option<&str> {
+        self.error_detail()
+            .and_then(|detail| detail.code.as_deref())
+    }
+
+    /// Request id of a failed request, for correlating with service logs
+    pub fn request_id(&self) -> Option<&str> {
+        match self {
+            Error::RequestFailed { request_id, .. } => request_id.as_deref(),
+            _ => None,
+        }
+    }
+
+    /// The index, document or other resource does not exist
+    pub fn is_not_found(&self) -> bool {
+        self.status() == Some(StatusCode::NOT_FOUND)
+    }
+
+    /// The service throttled the request
+    pub fn is_throttled(&self) -> bool {
+        matches!(
+            self.status(),
+            Some(StatusCode::TOO_MANY_REQUESTS | StatusCode::SERVICE_UNAVAILABLE)
+        )
+    }
+
+    /// The resource already exists or was modified concurrently
+    pub fn is_conflict(&self) -> bool {
+        matches!(
+            self.status(),
+            Some(StatusCode::CONFLICT | StatusCode::PRECONDITION_FAILED)
+        )
+    }
+
+    /// The failure is transient and the request may succeed if retried
+    pub fn is_retriable(&self) -> bool {
+        match self {
+            Error::RequestFailed { status, .. } => RetryPolicy::is_retriable_status(*status),
+            Error::Reqwest(err) => match err.status() {
+                Some(status) => RetryPolicy::is_retriable_status(status),
+                None => RetryPolicy::is_retriable_error(err),
+            },
+            _ => false,
+        }
+    }
+}
+
+/// A failed request of a split index batch
+#[derive(Debug)]
+pub struct BatchFailure {
+    /// Positions of the request's actions in the submitted batch
+    pub actions: Range<usize>,
+    /// Why the request failed
+    pub error: Error,
+}
+
+/// Request rejected by client-side validation before being sent
+#[derive(Debug, Error, Clone, PartialEq, Eq)]
+pub enum ValidationError {
+    /// Semantic queries need a semantic configuration
+    #[error("query type semantic requires a semantic configuration")]
+    SemanticConfigurationRequired,
+
+    /// Highlight tags only apply to highlighted fields
+    #[error("highlight tags require highlight fields")]
+    HighlightTagsWithoutHighlight,
+
+    /// Only one of the highlight pre/post tags was set
+    #[error("highlight pre and post tags must be set together")]
+    IncompleteHighlightTags,
+
+    /// `top` exceeds what the service returns in one response
+    #[error("top {top} exceeds the maximum of {max}")]
+    TopTooLarge { top: u32, max: u32 },
+
+    /// `skip` exceeds the service limit
+    #[error("skip {skip} exceeds the maximum of {max}")]
+    SkipTooLarge { skip: u32, max: u32 },
+
+    /// `minimumCoverage` must be a percentage
+    #[error("minimum coverage {0} must be between 0 and 100")]
+    InvalidMinimumCoverage(u8),
+
+    /// Search text is not valid Lucene syntax for `queryType=full`
+    #[error("invalid Lucene query: {0}")]
+    InvalidQuerySyntax(LuceneSyntaxError),
+
+    /// A facet parameter contains a character that separates facet parameters
+    #[error("facet {field} parameter {parameter} contains a separator character: {value:?}")]
+    InvalidFacetParameter {
+        field: String,
+        parameter: &'static str,
+        value: String,
+    },
+
+    /// A scoring parameter name is empty or contains `-`, which separates it
+    /// from the values
+    #[error("invalid scoring parameter name {0:?}")]
+    InvalidScoringParameterName(String),
+
+    /// A vector query needs a positive `k` and at least one field
+    #[error("vector query at position {position} needs k > 0 and at least one field")]
+    InvalidVectorQuery { position: usize },
+
+    /// A vector field references a profile missing from the index
+    #[error("vector field {field} references unknown vector search profile {profile}")]
+    UnknownVectorSearchProfile { field: String, profile: String },
+
+    /// A vector search profile references a missing algorithm, vectorizer or compression
+    #[error("vector search profile {profile} references unknown configuration {reference}")]
+    UnknownVectorSearchReference { profile: String, reference: String },
+
+    /// The semantic configuration is not defined in the index
+    #[error("semantic configuration {name} is not defined in the index")]
+    UnknownSemanticConfiguration { name: String },
+}
+
+/// OData error returned by Azure AI Search
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct ErrorDetail {
+    /// Error code (e.g., `InvalidRequestParameter`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub code: Option<String>,
+    /// Human readable error message
+    #[serde(default)]
+    pub message: String,
+    /// Target of the error (e.g., the offending parameter)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub target: Option<String>,
+    /// Nested errors
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub details: Vec<ErrorDetail>,
+    /// Additional service specific information
+    #[serde(rename = "innererror", skip_serializing_if = "Option::is_none")]
+    pub inner_error: Option<serde_json::Value>,
+}
+
+impl ErrorDetail {
+    /// Parse the `{"error": {...}}` envelope from a response body
+    pub fn from_body(body: &str) -> Option<Self> {
+        #[derive(Deserialize)]
+        struct Envelope {
+            error: ErrorDetail,
+        }
+
+        serde_json::from_str::<Envelope>(body)
+            .ok()
+            .map(|envelope| envelope.error)
+    }
+}
+
+fn failed_keys(failed: &[IndexingResult]) -> String {
+    failed
+        .iter()
+        .map(|result| result.key.as_str())
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+fn first_failure(failures: &[BatchFailure]) -> String {
+    match failures.first() {
+        Some(failure) => failure.error.to_string(),
+        None => "no failure reported".to_string(),
+    }
+}
+
 /// Result type alias for Azure Search operations
 pub type Result<T> = std::result::Result<T, Error>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::{TestResponse, TestServer};
+
+    const ODATA_ERROR: &str = r#"{
+        "error": {
+            "code": "InvalidRequestParameter",
+            "message": "The request is invalid. Details: top : Invalid value -1.",
+            "target": "top",
+            "details": [
+                {
+                    "code": "InvalidTopValue",
+                    "message": "Invalid value -1.",
+                    "target": "top"
+                }
+            ],
+            "innererror": {"trace": "abc"}
+        }
+    }"#;
+
+    fn request_failed(status: u16) -> Error {
+        Error::RequestFailed {
+            status: StatusCode::from_u16(status).unwrap(),
+            body: String::new(),
+            error: None,
+            request_id: None,
+        }
+    }
+
+    #[test]
+    fn parse_odata_error() {
+        let detail = ErrorDetail::from_body(ODATA_ERROR).unwrap();
+        assert_eq!(detail.code.as_deref(), Some("InvalidRequestParameter"));
+        assert_eq!(detail.target.as_deref(), Some("top"));
+        assert_eq!(detail.details.len(), 1);
+        assert_eq!(detail.details[0].code.as_deref(), Some("InvalidTopValue"));
+        assert_eq!(
+            detail.inner_error,
+            Some(serde_json::json!({"trace": "abc"}))
+        );
+
+        let detail = ErrorDetail::from_body(r#"{"error": {"message": "No index"}}"#).unwrap();
+        assert_eq!(detail.code, None);
+        assert_eq!(detail.message, "No index");
+
+        assert!(ErrorDetail::from_body("Service Unavailable").is_none());
+        assert!(ErrorDetail::from_body(r#"{"message": "not an envelope"}"#).is_none());
+    }
+
+    #[test]
+    fn status_helpers() {
+        assert!(request_failed(404).is_not_found());
+        assert!(!request_failed(404).is_retriable());
+        assert!(request_failed(429).is_throttled());
+        assert!(request_failed(503).is_throttled());
+        assert!(request_failed(503).is_retriable());
+        assert!(request_failed(409).is_conflict());
+        assert!(request_failed(412).is_conflict());
+        assert!(!request_failed(400).is_conflict());
+        assert!(!request_failed(400).is_retriable());
+        assert!(!Error::Configuration("missing".to_string()).is_retriable());
+        assert_eq!(Error::SearchError("failed".to_string()).status(), None);
+    }
+
+    #[test]
+    fn batch_failed_display() {
+        let err = Error::BatchFailed {
+            result: IndexDocumentsResult::default(),
+            failures: vec![BatchFailure {
+                actions: 0..2,
+                error: request_failed(503),
+            }],
+        };
+        assert_eq!(
+            err.to_string(),
+            "1 of the batch requests failed: Request failed with status 503 Service Unavailable: "
+        );
+        let err = Error::BatchFailed {
+            result: IndexDocumentsResult::default(),
+            failures: Vec::new(),
+        };
+        assert_eq!(
+            err.to_string(),
+            "0 of the batch requests failed: no failure reported"
+        );
+    }
+
+    #[tokio::test]
+    async fn failed_request_carries_error_and_request_id() {
+        let server = TestServer::start(|_| {
+            TestResponse::new(400, ODATA_ERROR)
+                .header("request-id", "3c0e4b8a-0f5f-4b8e-9a3e-2d9f0c4c1b7e")
+        })
+        .await;
+        let client = crate::AzureSearchClient::new(&server.url, "key", "2024-07-01", None).unwrap();
+
+        let err = client
+            .send_request::<serde_json::Value, ()>(reqwest::Method::GET, "indexes/hotels", None)
+            .await
+            .unwrap_err();
+        assert_eq!(err.status(), Some(StatusCode::BAD_REQUEST));
+        assert_eq!(err.error_code(), Some("InvalidRequestParameter"));
+        assert_eq!(
+            err.request_id(),
+            Some("3c0e4b8a-0f5f-4b8e-9a3e-2d9f0c4c1b7e")
+        );
+        assert_eq!(err.error_detail().unwrap().target.as_deref(), Some("top"));
+    }
+
+    #[tokio::test]
+    async fn ms_request_id_header() {
+        let server = TestServer::start(|_| {
+            TestResponse::new(404, r#"{"error": {"message": "No index"}}"#)
+                .header("x-ms-request-id", "abc")
+        })
+        .await;
+        let client = crate::AzureSearchClient::new(&server.url, "key", "2024-07-01", None).unwrap();
+
+        let err = client
+            .send_request::<serde_json::Value, ()>(reqwest::Method::GET, "indexes/hotels", None)
+            .await
+            .unwrap_err();
+        assert!(err.is_not_found());
+        assert_eq!(err.request_id(), Some("abc"));
+        assert_eq!(err.error_code(), None);
+    }
+}
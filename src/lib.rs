@@ -2,16 +2,27 @@
 //!
 //! Provides async Rust bindings for Azure AI Search (formerly Azure Cognitive Search)
 
+pub mod api_version;
 pub mod client;
+pub mod credential;
 pub mod error;
 pub mod models;
 pub mod operations;
+pub mod retry;
 
-pub use client::AzureSearchClient;
-pub use error::{Error, Result};
+#[cfg(test)]
+mod test_util;
+
+pub use api_version::ApiVersion;
+pub use client::{AzureSearchClient, AzureSearchClientBuilder};
+pub use credential::{Credential, TokenCredential};
+pub use error::{BatchFailure, Error, ErrorDetail, Result, ValidationError};
+pub use retry::RetryPolicy;
 
 /// Prelude module for convenient imports
 pub mod prelude {
-    pub use crate::error::{Error, Result};
-    pub use crate::AzureSearchClient;
+    pub use crate::credential::{Credential, TokenCredential};
+    pub use crate::error::{Error, ErrorDetail, Result};
+    pub use crate::retry::RetryPolicy;
+    pub use crate::{ApiVersion, AzureSearchClient, AzureSearchClientBuilder};
 }
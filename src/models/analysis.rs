@@ -0,0 +1,1433 @@
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+/// Custom analyzer, serialized with its `@odata.type`
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(tag = "@odata.type")]
+pub enum LexicalAnalyzer {
+    /// CustomAnalyzer
+    #[serde(rename = "#Microsoft.Azure.Search.CustomAnalyzer")]
+    Custom(CustomAnalyzer),
+    /// PatternAnalyzer
+    #[serde(rename = "#Microsoft.Azure.Search.PatternAnalyzer")]
+    Pattern(PatternAnalyzer),
+    /// StandardAnalyzer
+    #[serde(rename = "#Microsoft.Azure.Search.StandardAnalyzer")]
+    Standard(LuceneStandardAnalyzer),
+    /// StopAnalyzer
+    #[serde(rename = "#Microsoft.Azure.Search.StopAnalyzer")]
+    Stop(StopAnalyzer),
+    /// Unrecognized type, kept as received
+    #[serde(untagged)]
+    Unknown(Value),
+}
+
+impl LexicalAnalyzer {
+    /// Name referenced by fields and other analysis components
+    pub fn name(&self) -> Option<&str> {
+        match self {
+            LexicalAnalyzer::Custom(inner) => Some(&inner.name),
+            LexicalAnalyzer::Pattern(inner) => Some(&inner.name),
+            LexicalAnalyzer::Standard(inner) => Some(&inner.name),
+            LexicalAnalyzer::Stop(inner) => Some(&inner.name),
+            LexicalAnalyzer::Unknown(value) => value.get("name").and_then(Value::as_str),
+        }
+    }
+}
+
+impl From<CustomAnalyzer> for LexicalAnalyzer {
+    fn from(inner: CustomAnalyzer) -> Self {
+        LexicalAnalyzer::Custom(inner)
+    }
+}
+
+impl From<PatternAnalyzer> for LexicalAnalyzer {
+    fn from(inner: PatternAnalyzer) -> Self {
+        LexicalAnalyzer::Pattern(inner)
+    }
+}
+
+impl From<LuceneStandardAnalyzer> for LexicalAnalyzer {
+    fn from(inner: LuceneStandardAnalyzer) -> Self {
+        LexicalAnalyzer::Standard(inner)
+    }
+}
+
+impl From<StopAnalyzer> for LexicalAnalyzer {
+    fn from(inner: StopAnalyzer) -> Self {
+        LexicalAnalyzer::Stop(inner)
+    }
+}
+
+/// Tokenizer combined with token and char filters
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct CustomAnalyzer {
+    /// Name of the component
+    pub name: String,
+    /// Tokenizer name
+    pub tokenizer: String,
+    /// Token filters applied after the tokenizer, in order
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub token_filters: Option<Vec<String>>,
+    /// Char filters applied before the tokenizer, in order
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub char_filters: Option<Vec<String>>,
+    /// Properties not modeled by this version of the library
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+/// Splits text on a regular expression
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct PatternAnalyzer {
+    /// Name of the component
+    pub name: String,
+    /// Lowercase the terms
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lowercase: Option<bool>,
+    /// Regular expression matching separators
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pattern: Option<String>,
+    /// Regular expression flags, separated by `|`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub flags: Option<String>,
+    /// Stopwords to remove
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stopwords: Option<Vec<String>>,
+    /// Properties not modeled by this version of the library
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+/// Standard Lucene analyzer
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct LuceneStandardAnalyzer {
+    /// Name of the component
+    pub name: String,
+    /// Maximum token length
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_token_length: Option<u32>,
+    /// Stopwords to remove
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stopwords: Option<Vec<String>>,
+    /// Properties not modeled by this version of the library
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+/// Splits on non-letters, lowercases and removes stopwords
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct StopAnalyzer {
+    /// Name of the component
+    pub name: String,
+    /// Stopwords to remove
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stopwords: Option<Vec<String>>,
+    /// Properties not modeled by this version of the library
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+/// Custom tokenizer, serialized with its `@odata.type`
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(tag = "@odata.type")]
+pub enum LexicalTokenizer {
+    /// ClassicTokenizer
+    #[serde(rename = "#Microsoft.Azure.Search.ClassicTokenizer")]
+    Classic(ClassicTokenizer),
+    /// EdgeNGramTokenizer
+    #[serde(rename = "#Microsoft.Azure.Search.EdgeNGramTokenizer")]
+    EdgeNGram(EdgeNGramTokenizer),
+    /// KeywordTokenizerV2
+    #[serde(rename = "#Microsoft.Azure.Search.KeywordTokenizerV2")]
+    Keyword(KeywordTokenizer),
+    /// MicrosoftLanguageTokenizer
+    #[serde(rename = "#Microsoft.Azure.Search.MicrosoftLanguageTokenizer")]
+    MicrosoftLanguage(MicrosoftLanguageTokenizer),
+    /// MicrosoftLanguageStemmingTokenizer
+    #[serde(rename = "#Microsoft.Azure.Search.MicrosoftLanguageStemmingTokenizer")]
+    MicrosoftLanguageStemming(MicrosoftLanguageStemmingTokenizer),
+    /// NGramTokenizer
+    #[serde(rename = "#Microsoft.Azure.Search.NGramTokenizer")]
+    NGram(NGramTokenizer),
+    /// PathHierarchyTokenizerV2
+    #[serde(rename = "#Microsoft.Azure.Search.PathHierarchyTokenizerV2")]
+    PathHierarchy(PathHierarchyTokenizer),
+    /// PatternTokenizer
+    #[serde(rename = "#Microsoft.Azure.Search.PatternTokenizer")]
+    Pattern(PatternTokenizer),
+    /// StandardTokenizerV2
+    #[serde(rename = "#Microsoft.Azure.Search.StandardTokenizerV2")]
+    Standard(LuceneStandardTokenizer),
+    /// UaxUrlEmailTokenizer
+    #[serde(rename = "#Microsoft.Azure.Search.UaxUrlEmailTokenizer")]
+    UaxUrlEmail(UaxUrlEmailTokenizer),
+    /// Unrecognized type, kept as received
+    #[serde(untagged)]
+    Unknown(Value),
+}
+
+impl LexicalTokenizer {
+    /// Name referenced by fields and other analysis components
+    pub fn name(&self) -> Option<&str> {
+        match self {
+            LexicalTokenizer::Classic(inner) => Some(&inner.name),
+            LexicalTokenizer::EdgeNGram(inner) => Some(&inner.name),
+            LexicalTokenizer::Keyword(inner) => Some(&inner.name),
+            LexicalTokenizer::MicrosoftLanguage(inner) => Some(&inner.name),
+            LexicalTokenizer::MicrosoftLanguageStemming(inner) => Some(&inner.name),
+            LexicalTokenizer::NGram(inner) => Some(&inner.name),
+            LexicalTokenizer::PathHierarchy(inner) => Some(&inner.name),
+            LexicalTokenizer::Pattern(inner) => Some(&inner.name),
+            LexicalTokenizer::Standard(inner) => Some(&inner.name),
+            LexicalTokenizer::UaxUrlEmail(inner) => Some(&inner.name),
+            LexicalTokenizer::Unknown(value) => value.get("name").and_then(Value::as_str),
+        }
+    }
+}
+
+impl From<ClassicTokenizer> for LexicalTokenizer {
+    fn from(inner: ClassicTokenizer) -> Self {
+        LexicalTokenizer::Classic(inner)
+    }
+}
+
+impl From<EdgeNGramTokenizer> for LexicalTokenizer {
+    fn from(inner: EdgeNGramTokenizer) -> Self {
+        LexicalTokenizer::EdgeNGram(inner)
+    }
+}
+
+impl From<KeywordTokenizer> for LexicalTokenizer {
+    fn from(inner: KeywordTokenizer) -> Self {
+        LexicalTokenizer::Keyword(inner)
+    }
+}
+
+impl From<MicrosoftLanguageTokenizer> for LexicalTokenizer {
+    fn from(inner: MicrosoftLanguageTokenizer) -> Self {
+        LexicalTokenizer::MicrosoftLanguage(inner)
+    }
+}
+
+impl From<MicrosoftLanguageStemmingTokenizer> for LexicalTokenizer {
+    fn from(inner: MicrosoftLanguageStemmingTokenizer) -> Self {
+        LexicalTokenizer::MicrosoftLanguageStemming(inner)
+    }
+}
+
+impl From<NGramTokenizer> for LexicalTokenizer {
+    fn from(inner: NGramTokenizer) -> Self {
+        LexicalTokenizer::NGram(inner)
+    }
+}
+
+impl From<PathHierarchyTokenizer> for LexicalTokenizer {
+    fn from(inner: PathHierarchyTokenizer) -> Self {
+        LexicalTokenizer::PathHierarchy(inner)
+    }
+}
+
+impl From<PatternTokenizer> for LexicalTokenizer {
+    fn from(inner: PatternTokenizer) -> Self {
+        LexicalTokenizer::Pattern(inner)
+    }
+}
+
+impl From<LuceneStandardTokenizer> for LexicalTokenizer {
+    fn from(inner: LuceneStandardTokenizer) -> Self {
+        LexicalTokenizer::Standard(inner)
+    }
+}
+
+impl From<UaxUrlEmailTokenizer> for LexicalTokenizer {
+    fn from(inner: UaxUrlEmailTokenizer) -> Self {
+        LexicalTokenizer::UaxUrlEmail(inner)
+    }
+}
+
+/// Grammar based tokenizer for European languages
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ClassicTokenizer {
+    /// Name of the component
+    pub name: String,
+    /// Maximum token length
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_token_length: Option<u32>,
+    /// Properties not modeled by this version of the library
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+/// Emits n-grams anchored at the start of each token
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct EdgeNGramTokenizer {
+    /// Name of the component
+    pub name: String,
+    /// Minimum n-gram length
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min_gram: Option<u32>,
+    /// Maximum n-gram length
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_gram: Option<u32>,
+    /// Character classes kept in tokens
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub token_chars: Option<Vec<TokenCharacterKind>>,
+    /// Properties not modeled by this version of the library
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+/// Emits the entire input as a single token
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct KeywordTokenizer {
+    /// Name of the component
+    pub name: String,
+    /// Maximum token length
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_token_length: Option<u32>,
+    /// Properties not modeled by this version of the library
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+/// Language specific tokenizer
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct MicrosoftLanguageTokenizer {
+    /// Name of the component
+    pub name: String,
+    /// Maximum token length
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_token_length: Option<u32>,
+    /// Whether the tokenizer is used at query time
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_search_tokenizer: Option<bool>,
+    /// Language (e.g. `english`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub language: Option<String>,
+    /// Properties not modeled by this version of the library
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+/// Language specific tokenizer that reduces words to their base forms
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct MicrosoftLanguageStemmingTokenizer {
+    /// Name of the component
+    pub name: String,
+    /// Maximum token length
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_token_length: Option<u32>,
+    /// Whether the tokenizer is used at query time
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_search_tokenizer: Option<bool>,
+    /// Language (e.g. `english`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub language: Option<String>,
+    /// Properties not modeled by this version of the library
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+/// Emits n-grams of the input
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct NGramTokenizer {
+    /// Name of the component
+    pub name: String,
+    /// Minimum n-gram length
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min_gram: Option<u32>,
+    /// Maximum n-gram length
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_gram: Option<u32>,
+    /// Character classes kept in tokens
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub token_chars: Option<Vec<TokenCharacterKind>>,
+    /// Properties not modeled by this version of the library
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+/// Emits every level of a path-like hierarchy
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct PathHierarchyTokenizer {
+    /// Name of the component
+    pub name: String,
+    /// Delimiter character
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub delimiter: Option<String>,
+    /// Character replacing the delimiter
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub replacement: Option<String>,
+    /// Maximum token length
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_token_length: Option<u32>,
+    /// Emit tokens in reverse order
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reverse: Option<bool>,
+    /// Number of initial tokens to skip
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub skip: Option<u32>,
+    /// Properties not modeled by this version of the library
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+/// Splits text or extracts tokens with a regular expression
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct PatternTokenizer {
+    /// Name of the component
+    pub name: String,
+    /// Regular expression
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pattern: Option<String>,
+    /// Regular expression flags, separated by `|`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub flags: Option<String>,
+    /// Capture group extracted as tokens, or -1 to split
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub group: Option<i32>,
+    /// Properties not modeled by this version of the library
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+/// Unicode text segmentation tokenizer
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct LuceneStandardTokenizer {
+    /// Name of the component
+    pub name: String,
+    /// Maximum token length
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_token_length: Option<u32>,
+    /// Properties not modeled by this version of the library
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+/// Standard tokenizer that keeps URLs and emails as single tokens
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct UaxUrlEmailTokenizer {
+    /// Name of the component
+    pub name: String,
+    /// Maximum token length
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_token_length: Option<u32>,
+    /// Properties not modeled by this version of the library
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+/// Custom token filter, serialized with its `@odata.type`
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(tag = "@odata.type")]
+pub enum TokenFilter {
+    /// AsciiFoldingTokenFilter
+    #[serde(rename = "#Microsoft.Azure.Search.AsciiFoldingTokenFilter")]
+    AsciiFolding(AsciiFoldingTokenFilter),
+    /// CjkBigramTokenFilter
+    #[serde(rename = "#Microsoft.Azure.Search.CjkBigramTokenFilter")]
+    CjkBigram(CjkBigramTokenFilter),
+    /// CommonGramTokenFilter
+    #[serde(rename = "#Microsoft.Azure.Search.CommonGramTokenFilter")]
+    CommonGram(CommonGramTokenFilter),
+    /// DictionaryDecompounderTokenFilter
+    #[serde(rename = "#Microsoft.Azure.Search.DictionaryDecompounderTokenFilter")]
+    DictionaryDecompounder(DictionaryDecompounderTokenFilter),
+    /// EdgeNGramTokenFilterV2
+    #[serde(rename = "#Microsoft.Azure.Search.EdgeNGramTokenFilterV2")]
+    EdgeNGram(EdgeNGramTokenFilter),
+    /// ElisionTokenFilter
+    #[serde(rename = "#Microsoft.Azure.Search.ElisionTokenFilter")]
+    Elision(ElisionTokenFilter),
+    /// KeepTokenFilter
+    #[serde(rename = "#Microsoft.Azure.Search.KeepTokenFilter")]
+    Keep(KeepTokenFilter),
+    /// KeywordMarkerTokenFilter
+    #[serde(rename = "#Microsoft.Azure.Search.KeywordMarkerTokenFilter")]
+    KeywordMarker(KeywordMarkerTokenFilter),
+    /// LengthTokenFilter
+    #[serde(rename = "#Microsoft.Azure.Search.LengthTokenFilter")]
+    Length(LengthTokenFilter),
+    /// LimitTokenFilter
+    #[serde(rename = "#Microsoft.Azure.Search.LimitTokenFilter")]
+    Limit(LimitTokenFilter),
+    /// NGramTokenFilterV2
+    #[serde(rename = "#Microsoft.Azure.Search.NGramTokenFilterV2")]
+    NGram(NGramTokenFilter),
+    /// PatternCaptureTokenFilter
+    #[serde(rename = "#Microsoft.Azure.Search.PatternCaptureTokenFilter")]
+    PatternCapture(PatternCaptureTokenFilter),
+    /// PatternReplaceTokenFilter
+    #[serde(rename = "#Microsoft.Azure.Search.PatternReplaceTokenFilter")]
+    PatternReplace(PatternReplaceTokenFilter),
+    /// PhoneticTokenFilter
+    #[serde(rename = "#Microsoft.Azure.Search.PhoneticTokenFilter")]
+    Phonetic(PhoneticTokenFilter),
+    /// ShingleTokenFilter
+    #[serde(rename = "#Microsoft.Azure.Search.ShingleTokenFilter")]
+    Shingle(ShingleTokenFilter),
+    /// SnowballTokenFilter
+    #[serde(rename = "#Microsoft.Azure.Search.SnowballTokenFilter")]
+    Snowball(SnowballTokenFilter),
+    /// StemmerTokenFilter
+    #[serde(rename = "#Microsoft.Azure.Search.StemmerTokenFilter")]
+    Stemmer(StemmerTokenFilter),
+    /// StemmerOverrideTokenFilter
+    #[serde(rename = "#Microsoft.Azure.Search.StemmerOverrideTokenFilter")]
+    StemmerOverride(StemmerOverrideTokenFilter),
+    /// StopwordsTokenFilter
+    #[serde(rename = "#Microsoft.Azure.Search.StopwordsTokenFilter")]
+    Stopwords(StopwordsTokenFilter),
+    /// SynonymTokenFilter
+    #[serde(rename = "#Microsoft.Azure.Search.SynonymTokenFilter")]
+    Synonym(SynonymTokenFilter),
+    /// TruncateTokenFilter
+    #[serde(rename = "#Microsoft.Azure.Search.TruncateTokenFilter")]
+    Truncate(TruncateTokenFilter),
+    /// UniqueTokenFilter
+    #[serde(rename = "#Microsoft.Azure.Search.UniqueTokenFilter")]
+    Unique(UniqueTokenFilter),
+    /// WordDelimiterTokenFilter
+    #[serde(rename = "#Microsoft.Azure.Search.WordDelimiterTokenFilter")]
+    WordDelimiter(WordDelimiterTokenFilter),
+    /// Unrecognized type, kept as received
+    #[serde(untagged)]
+    Unknown(Value),
+}
+
+impl TokenFilter {
+    /// Name referenced by fields and other analysis components
+    pub fn name(&self) -> Option<&str> {
+        match self {
+            TokenFilter::AsciiFolding(inner) => Some(&inner.name),
+            TokenFilter::CjkBigram(inner) => Some(&inner.name),
+            TokenFilter::CommonGram(inner) => Some(&inner.name),
+            TokenFilter::DictionaryDecompounder(inner) => Some(&inner.name),
+            TokenFilter::EdgeNGram(inner) => Some(&inner.name),
+            TokenFilter::Elision(inner) => Some(&inner.name),
+            TokenFilter::Keep(inner) => Some(&inner.name),
+            TokenFilter::KeywordMarker(inner) => Some(&inner.name),
+            TokenFilter::Length(inner) => Some(&inner.name),
+            TokenFilter::Limit(inner) => Some(&inner.name),
+            TokenFilter::NGram(inner) => Some(&inner.name),
+            TokenFilter::PatternCapture(inner) => Some(&inner.name),
+            TokenFilter::PatternReplace(inner) => Some(&inner.name),
+            TokenFilter::Phonetic(inner) => Some(&inner.name),
+            TokenFilter::Shingle(inner) => Some(&inner.name),
+            TokenFilter::Snowball(inner) => Some(&inner.name),
+            TokenFilter::Stemmer(inner) => Some(&inner.name),
+            TokenFilter::StemmerOverride(inner) => Some(&inner.name),
+            TokenFilter::Stopwords(inner) => Some(&inner.name),
+            TokenFilter::Synonym(inner) => Some(&inner.name),
+            TokenFilter::Truncate(inner) => Some(&inner.name),
+            TokenFilter::Unique(inner) => Some(&inner.name),
+            TokenFilter::WordDelimiter(inner) => Some(&inner.name),
+            TokenFilter::Unknown(value) => value.get("name").and_then(Value::as_str),
+        }
+    }
+}
+
+impl From<AsciiFoldingTokenFilter> for TokenFilter {
+    fn from(inner: AsciiFoldingTokenFilter) -> Self {
+        TokenFilter::AsciiFolding(inner)
+    }
+}
+
+impl From<CjkBigramTokenFilter> for TokenFilter {
+    fn from(inner: CjkBigramTokenFilter) -> Self {
+        TokenFilter::CjkBigram(inner)
+    }
+}
+
+impl From<CommonGramTokenFilter> for TokenFilter {
+    fn from(inner: CommonGramTokenFilter) -> Self {
+        TokenFilter::CommonGram(inner)
+    }
+}
+
+impl From<DictionaryDecompounderTokenFilter> for TokenFilter {
+    fn from(inner: DictionaryDecompounderTokenFilter) -> Self {
+        TokenFilter::DictionaryDecompounder(inner)
+    }
+}
+
+impl From<EdgeNGramTokenFilter> for TokenFilter {
+    fn from(inner: EdgeNGramTokenFilter) -> Self {
+        TokenFilter::EdgeNGram(inner)
+    }
+}
+
+impl From<ElisionTokenFilter> for TokenFilter {
+    fn from(inner: ElisionTokenFilter) -> Self {
+        TokenFilter::Elision(inner)
+    }
+}
+
+impl From<KeepTokenFilter> for TokenFilter {
+    fn from(inner: KeepTokenFilter) -> Self {
+        TokenFilter::Keep(inner)
+    }
+}
+
+impl From<KeywordMarkerTokenFilter> for TokenFilter {
+    fn from(inner: KeywordMarkerTokenFilter) -> Self {
+        TokenFilter::KeywordMarker(inner)
+    }
+}
+
+impl From<LengthTokenFilter> for TokenFilter {
+    fn from(inner: LengthTokenFilter) -> Self {
+        TokenFilter::Length(inner)
+    }
+}
+
+impl From<LimitTokenFilter> for TokenFilter {
+    fn from(inner: LimitTokenFilter) -> Self {
+        TokenFilter::Limit(inner)
+    }
+}
+
+impl From<NGramTokenFilter> for TokenFilter {
+    fn from(inner: NGramTokenFilter) -> Self {
+        TokenFilter::NGram(inner)
+    }
+}
+
+impl From<PatternCaptureTokenFilter> for TokenFilter {
+    fn from(inner: PatternCaptureTokenFilter) -> Self {
+        TokenFilter::PatternCapture(inner)
+    }
+}
+
+impl From<PatternReplaceTokenFilter> for TokenFilter {
+    fn from(inner: PatternReplaceTokenFilter) -> Self {
+        TokenFilter::PatternReplace(inner)
+    }
+}
+
+impl From<PhoneticTokenFilter> for TokenFilter {
+    fn from(inner: PhoneticTokenFilter) -> Self {
+        TokenFilter::Phonetic(inner)
+    }
+}
+
+impl From<ShingleTokenFilter> for TokenFilter {
+    fn from(inner: ShingleTokenFilter) -> Self {
+        TokenFilter::Shingle(inner)
+    }
+}
+
+impl From<SnowballTokenFilter> for TokenFilter {
+    fn from(inner: SnowballTokenFilter) -> Self {
+        TokenFilter::Snowball(inner)
+    }
+}
+
+impl From<StemmerTokenFilter> for TokenFilter {
+    fn from(inner: StemmerTokenFilter) -> Self {
+        TokenFilter::Stemmer(inner)
+    }
+}
+
+impl From<StemmerOverrideTokenFilter> for TokenFilter {
+    fn from(inner: StemmerOverrideTokenFilter) -> Self {
+        TokenFilter::StemmerOverride(inner)
+    }
+}
+
+impl From<StopwordsTokenFilter> for TokenFilter {
+    fn from(inner: StopwordsTokenFilter) -> Self {
+        TokenFilter::Stopwords(inner)
+    }
+}
+
+impl From<SynonymTokenFilter> for TokenFilter {
+    fn from(inner: SynonymTokenFilter) -> Self {
+        TokenFilter::Synonym(inner)
+    }
+}
+
+impl From<TruncateTokenFilter> for TokenFilter {
+    fn from(inner: TruncateTokenFilter) -> Self {
+        TokenFilter::Truncate(inner)
+    }
+}
+
+impl From<UniqueTokenFilter> for TokenFilter {
+    fn from(inner: UniqueTokenFilter) -> Self {
+        TokenFilter::Unique(inner)
+    }
+}
+
+impl From<WordDelimiterTokenFilter> for TokenFilter {
+    fn from(inner: WordDelimiterTokenFilter) -> Self {
+        TokenFilter::WordDelimiter(inner)
+    }
+}
+
+/// Converts non-ASCII characters to their ASCII equivalents
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct AsciiFoldingTokenFilter {
+    /// Name of the component
+    pub name: String,
+    /// Also keep the original token
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub preserve_original: Option<bool>,
+    /// Properties not modeled by this version of the library
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+/// Forms bigrams of CJK terms
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct CjkBigramTokenFilter {
+    /// Name of the component
+    pub name: String,
+    /// Scripts to leave alone
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ignore_scripts: Option<Vec<CjkBigramScript>>,
+    /// Also emit unigrams
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub output_unigrams: Option<bool>,
+    /// Properties not modeled by this version of the library
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+/// Forms bigrams of frequent terms
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct CommonGramTokenFilter {
+    /// Name of the component
+    pub name: String,
+    /// Frequent terms
+    pub common_words: Vec<String>,
+    /// Match common words case-insensitively
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ignore_case: Option<bool>,
+    /// Only emit the bigrams
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub query_mode: Option<bool>,
+    /// Properties not modeled by this version of the library
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+/// Splits compound words found in a dictionary
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct DictionaryDecompounderTokenFilter {
+    /// Name of the component
+    pub name: String,
+    /// Dictionary of sub-words
+    pub word_list: Vec<String>,
+    /// Minimum size of words to decompound
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min_word_size: Option<u32>,
+    /// Minimum size of emitted sub-words
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min_subword_size: Option<u32>,
+    /// Maximum size of emitted sub-words
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_subword_size: Option<u32>,
+    /// Only emit the longest matching sub-word
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub only_longest_match: Option<bool>,
+    /// Properties not modeled by this version of the library
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+/// Emits n-grams anchored at one side of each token
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct EdgeNGramTokenFilter {
+    /// Name of the component
+    pub name: String,
+    /// Minimum n-gram length
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min_gram: Option<u32>,
+    /// Maximum n-gram length
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_gram: Option<u32>,
+    /// Side the n-grams are anchored at
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub side: Option<EdgeNGramTokenFilterSide>,
+    /// Properties not modeled by this version of the library
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+/// Removes elisions (e.g. `l'avion` becomes `avion`)
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ElisionTokenFilter {
+    /// Name of the component
+    pub name: String,
+    /// Articles to remove
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub articles: Option<Vec<String>>,
+    /// Properties not modeled by this version of the library
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+/// Only keeps tokens from a list
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct KeepTokenFilter {
+    /// Name of the component
+    pub name: String,
+    /// Tokens to keep
+    pub keep_words: Vec<String>,
+    /// Lowercase the tokens before matching
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub keep_words_case: Option<bool>,
+    /// Properties not modeled by this version of the library
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+/// Protects terms from stemming
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct KeywordMarkerTokenFilter {
+    /// Name of the component
+    pub name: String,
+    /// Protected terms
+    pub keywords: Vec<String>,
+    /// Match terms case-insensitively
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ignore_case: Option<bool>,
+    /// Properties not modeled by this version of the library
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+/// Removes tokens that are too short or too long
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct LengthTokenFilter {
+    /// Name of the component
+    pub name: String,
+    /// Minimum length
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min: Option<u32>,
+    /// Maximum length
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max: Option<u32>,
+    /// Properties not modeled by this version of the library
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+/// Limits the number of tokens
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct LimitTokenFilter {
+    /// Name of the component
+    pub name: String,
+    /// Maximum number of tokens
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_token_count: Option<u32>,
+    /// Consume all tokens even after the limit
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub consume_all_tokens: Option<bool>,
+    /// Properties not modeled by this version of the library
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+/// Emits n-grams of each token
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct NGramTokenFilter {
+    /// Name of the component
+    pub name: String,
+    /// Minimum n-gram length
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min_gram: Option<u32>,
+    /// Maximum n-gram length
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_gram: Option<u32>,
+    /// Properties not modeled by this version of the library
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+/// Emits the capture groups of regular expressions
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct PatternCaptureTokenFilter {
+    /// Name of the component
+    pub name: String,
+    /// Regular expressions
+    pub patterns: Vec<String>,
+    /// Also keep the original token
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub preserve_original: Option<bool>,
+    /// Properties not modeled by this version of the library
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+/// Replaces regular expression matches in each token
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct PatternReplaceTokenFilter {
+    /// Name of the component
+    pub name: String,
+    /// Regular expression
+    pub pattern: String,
+    /// Replacement text
+    pub replacement: String,
+    /// Properties not modeled by this version of the library
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+/// Emits phonetic encodings of tokens
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct PhoneticTokenFilter {
+    /// Name of the component
+    pub name: String,
+    /// Phonetic encoder (e.g. `doubleMetaphone`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub encoder: Option<String>,
+    /// Replace the original token instead of adding to it
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub replace: Option<bool>,
+    /// Properties not modeled by this version of the library
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+/// Emits combinations of adjacent tokens
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ShingleTokenFilter {
+    /// Name of the component
+    pub name: String,
+    /// Maximum shingle size
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_shingle_size: Option<u32>,
+    /// Minimum shingle size
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min_shingle_size: Option<u32>,
+    /// Also emit single tokens
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub output_unigrams: Option<bool>,
+    /// Emit single tokens when no shingles are available
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub output_unigrams_if_no_shingles: Option<bool>,
+    /// String joining tokens in a shingle
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub token_separator: Option<String>,
+    /// String inserted for removed tokens
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub filter_token: Option<String>,
+    /// Properties not modeled by this version of the library
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+/// Snowball stemmer
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct SnowballTokenFilter {
+    /// Name of the component
+    pub name: String,
+    /// Language (e.g. `english`)
+    pub language: String,
+    /// Properties not modeled by this version of the library
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+/// Language specific stemmer
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct StemmerTokenFilter {
+    /// Name of the component
+    pub name: String,
+    /// Language (e.g. `lightEnglish`)
+    pub language: String,
+    /// Properties not modeled by this version of the library
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+/// Overrides stemming with custom rules
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct StemmerOverrideTokenFilter {
+    /// Name of the component
+    pub name: String,
+    /// Rules in the form `word => stem`
+    pub rules: Vec<String>,
+    /// Properties not modeled by this version of the library
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+/// Removes stopwords
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct StopwordsTokenFilter {
+    /// Name of the component
+    pub name: String,
+    /// Stopwords to remove
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stopwords: Option<Vec<String>>,
+    /// Predefined stopword list (e.g. `english`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stopwords_list: Option<String>,
+    /// Match stopwords case-insensitively
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ignore_case: Option<bool>,
+    /// Also remove a trailing stopword
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub remove_trailing: Option<bool>,
+    /// Properties not modeled by this version of the library
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+/// Expands or replaces synonyms
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct SynonymTokenFilter {
+    /// Name of the component
+    pub name: String,
+    /// Synonym rules (e.g. `ipod, i-pod => ipod`)
+    pub synonyms: Vec<String>,
+    /// Match synonyms case-insensitively
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ignore_case: Option<bool>,
+    /// Map equivalent synonyms to each other
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expand: Option<bool>,
+    /// Properties not modeled by this version of the library
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+/// Truncates tokens to a maximum length
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct TruncateTokenFilter {
+    /// Name of the component
+    pub name: String,
+    /// Maximum length
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub length: Option<u32>,
+    /// Properties not modeled by this version of the library
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+/// Removes duplicate tokens
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct UniqueTokenFilter {
+    /// Name of the component
+    pub name: String,
+    /// Only remove duplicates at the same position
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub only_on_same_position: Option<bool>,
+    /// Properties not modeled by this version of the library
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+/// Splits words into sub-words
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct WordDelimiterTokenFilter {
+    /// Name of the component
+    pub name: String,
+    /// Emit word parts
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub generate_word_parts: Option<bool>,
+    /// Emit number parts
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub generate_number_parts: Option<bool>,
+    /// Join runs of word parts
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub catenate_words: Option<bool>,
+    /// Join runs of number parts
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub catenate_numbers: Option<bool>,
+    /// Join runs of all parts
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub catenate_all: Option<bool>,
+    /// Split on case changes
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub split_on_case_change: Option<bool>,
+    /// Also keep the original token
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub preserve_original: Option<bool>,
+    /// Split on numbers
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub split_on_numerics: Option<bool>,
+    /// Remove trailing `'s`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stem_english_possessive: Option<bool>,
+    /// Tokens not to split
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub protected_words: Option<Vec<String>>,
+    /// Properties not modeled by this version of the library
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+/// Custom char filter, serialized with its `@odata.type`
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(tag = "@odata.type")]
+pub enum CharFilter {
+    /// MappingCharFilter
+    #[serde(rename = "#Microsoft.Azure.Search.MappingCharFilter")]
+    Mapping(MappingCharFilter),
+    /// PatternReplaceCharFilter
+    #[serde(rename = "#Microsoft.Azure.Search.PatternReplaceCharFilter")]
+    PatternReplace(PatternReplaceCharFilter),
+    /// Unrecognized type, kept as received
+    #[serde(untagged)]
+    Unknown(Value),
+}
+
+impl CharFilter {
+    /// Name referenced by fields and other analysis components
+    pub fn name(&self) -> Option<&str> {
+        match self {
+            CharFilter::Mapping(inner) => Some(&inner.name),
+            CharFilter::PatternReplace(inner) => Some(&inner.name),
+            CharFilter::Unknown(value) => value.get("name").and_then(Value::as_str),
+        }
+    }
+}
+
+impl From<MappingCharFilter> for CharFilter {
+    fn from(inner: MappingCharFilter) -> Self {
+        CharFilter::Mapping(inner)
+    }
+}
+
+impl From<PatternReplaceCharFilter> for CharFilter {
+    fn from(inner: PatternReplaceCharFilter) -> Self {
+        CharFilter::PatternReplace(inner)
+    }
+}
+
+/// Replaces characters using mapping rules
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct MappingCharFilter {
+    /// Name of the component
+    pub name: String,
+    /// Rules in the form `a=>b`
+    pub mappings: Vec<String>,
+    /// Properties not modeled by this version of the library
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+/// Replaces regular expression matches in the input
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct PatternReplaceCharFilter {
+    /// Name of the component
+    pub name: String,
+    /// Regular expression
+    pub pattern: String,
+    /// Replacement text
+    pub replacement: String,
+    /// Properties not modeled by this version of the library
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+/// Custom normalizer, serialized with its `@odata.type`
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(tag = "@odata.type")]
+pub enum LexicalNormalizer {
+    /// CustomNormalizer
+    #[serde(rename = "#Microsoft.Azure.Search.CustomNormalizer")]
+    Custom(CustomNormalizer),
+    /// Unrecognized type, kept as received
+    #[serde(untagged)]
+    Unknown(Value),
+}
+
+impl LexicalNormalizer {
+    /// Name referenced by fields and other analysis components
+    pub fn name(&self) -> Option<&str> {
+        match self {
+            LexicalNormalizer::Custom(inner) => Some(&inner.name),
+            LexicalNormalizer::Unknown(value) => value.get("name").and_then(Value::as_str),
+        }
+    }
+}
+
+impl From<CustomNormalizer> for LexicalNormalizer {
+    fn from(inner: CustomNormalizer) -> Self {
+        LexicalNormalizer::Custom(inner)
+    }
+}
+
+/// Token and char filters applied to filterable, sortable and facetable fields
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct CustomNormalizer {
+    /// Name of the component
+    pub name: String,
+    /// Token filters, in order
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub token_filters: Option<Vec<String>>,
+    /// Char filters, in order
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub char_filters: Option<Vec<String>>,
+    /// Properties not modeled by this version of the library
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+impl CustomAnalyzer {
+    /// Create an analyzer from a tokenizer
+    pub fn new(name: impl Into<String>, tokenizer: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            tokenizer: tokenizer.into(),
+            ..Default::default()
+        }
+    }
+
+    /// Add a token filter applied after the tokenizer
+    pub fn token_filter(mut self, token_filter: impl Into<String>) -> Self {
+        self.token_filters
+            .get_or_insert_with(Vec::new)
+            .push(token_filter.into());
+        self
+    }
+
+    /// Add a char filter applied before the tokenizer
+    pub fn char_filter(mut self, char_filter: impl Into<String>) -> Self {
+        self.char_filters
+            .get_or_insert_with(Vec::new)
+            .push(char_filter.into());
+        self
+    }
+}
+
+impl CustomNormalizer {
+    /// Create an empty normalizer
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            ..Default::default()
+        }
+    }
+
+    /// Add a token filter
+    pub fn token_filter(mut self, token_filter: impl Into<String>) -> Self {
+        self.token_filters
+            .get_or_insert_with(Vec::new)
+            .push(token_filter.into());
+        self
+    }
+
+    /// Add a char filter
+    pub fn char_filter(mut self, char_filter: impl Into<String>) -> Self {
+        self.char_filters
+            .get_or_insert_with(Vec::new)
+            .push(char_filter.into());
+        self
+    }
+}
+
+/// Character class kept in n-gram tokens
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum TokenCharacterKind {
+    /// letter
+    Letter,
+    /// digit
+    Digit,
+    /// whitespace
+    Whitespace,
+    /// punctuation
+    Punctuation,
+    /// symbol
+    Symbol,
+}
+
+/// Script ignored by the CJK bigram filter
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum CjkBigramScript {
+    /// han
+    Han,
+    /// hiragana
+    Hiragana,
+    /// katakana
+    Katakana,
+    /// hangul
+    Hangul,
+}
+
+/// Side of the token an edge n-gram is anchored at
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum EdgeNGramTokenFilterSide {
+    /// front
+    Front,
+    /// back
+    Back,
+}
+
+#[cfg(test)]
+mod tests {
+    use serde::de::DeserializeOwned;
+    use serde_json::json;
+
+    use super::*;
+
+    fn round_trip<T: Serialize + DeserializeOwned>(value: Value) -> T {
+        let parsed: T = serde_json::from_value(value.clone()).unwrap();
+        assert_eq!(serde_json::to_value(&parsed).unwrap(), value);
+        parsed
+    }
+
+    #[test]
+    fn analyzers_round_trip() {
+        let analyzer: LexicalAnalyzer = round_trip(json!({
+            "@odata.type": "#Microsoft.Azure.Search.CustomAnalyzer",
+            "name": "my_analyzer",
+            "tokenizer": "standard_v2",
+            "tokenFilters": ["lowercase"],
+            "futureOption": 3
+        }));
+        match &analyzer {
+            LexicalAnalyzer::Custom(custom) => {
+                assert_eq!(custom.tokenizer, "standard_v2");
+                assert_eq!(custom.extra["futureOption"], 3);
+                assert!(!custom.extra.contains_key("@odata.type"));
+            }
+            other => panic!("{:?}", other),
+        }
+
+        let analyzer: LexicalAnalyzer = round_trip(json!({
+            "@odata.type": "#Microsoft.Azure.Search.FutureAnalyzer",
+            "name": "future"
+        }));
+        assert!(matches!(analyzer, LexicalAnalyzer::Unknown(_)));
+        assert_eq!(analyzer.name(), Some("future"));
+    }
+
+    #[test]
+    fn tokenizers_round_trip() {
+        let tokenizer: LexicalTokenizer = round_trip(json!({
+            "@odata.type": "#Microsoft.Azure.Search.EdgeNGramTokenizer",
+            "name": "edge",
+            "minGram": 2,
+            "maxGram": 10,
+            "tokenChars": ["letter", "digit"],
+            "futureOption": "x"
+        }));
+        match &tokenizer {
+            LexicalTokenizer::EdgeNGram(edge) => {
+                assert_eq!(edge.min_gram, Some(2));
+                assert_eq!(edge.extra["futureOption"], "x");
+            }
+            other => panic!("{:?}", other),
+        }
+
+        let tokenizer: LexicalTokenizer = round_trip(json!({
+            "@odata.type": "#Microsoft.Azure.Search.FutureTokenizer",
+            "name": "future",
+            "setting": [1, 2]
+        }));
+        match &tokenizer {
+            LexicalTokenizer::Unknown(value) => assert_eq!(
+                value["@odata.type"],
+                "#Microsoft.Azure.Search.FutureTokenizer"
+            ),
+            other => panic!("{:?}", other),
+        }
+    }
+
+    #[test]
+    fn token_filters_round_trip() {
+        let filter: TokenFilter = round_trip(json!({
+            "@odata.type": "#Microsoft.Azure.Search.StopwordsTokenFilter",
+            "name": "stop",
+            "stopwordsList": "english",
+            "ignoreCase": true,
+            "futureOption": null
+        }));
+        match &filter {
+            TokenFilter::Stopwords(stop) => assert_eq!(stop.extra["futureOption"], Value::Null),
+            other => panic!("{:?}", other),
+        }
+        assert_eq!(filter.name(), Some("stop"));
+
+        let filter: TokenFilter = round_trip(json!({
+            "@odata.type": "#Microsoft.Azure.Search.FutureTokenFilter",
+            "name": "future"
+        }));
+        match &filter {
+            TokenFilter::Unknown(value) => assert_eq!(
+                value["@odata.type"],
+                "#Microsoft.Azure.Search.FutureTokenFilter"
+            ),
+            other => panic!("{:?}", other),
+        }
+    }
+
+    #[test]
+    fn char_filters_round_trip() {
+        let filter: CharFilter = round_trip(json!({
+            "@odata.type": "#Microsoft.Azure.Search.MappingCharFilter",
+            "name": "map",
+            "mappings": ["a=>b"],
+            "futureOption": { "nested": true }
+        }));
+        match &filter {
+            CharFilter::Mapping(mapping) => {
+                assert_eq!(mapping.mappings, ["a=>b"]);
+                assert_eq!(mapping.extra["futureOption"], json!({ "nested": true }));
+            }
+            other => panic!("{:?}", other),
+        }
+
+        let filter: CharFilter = round_trip(json!({
+            "@odata.type": "#Microsoft.Azure.Search.FutureCharFilter",
+            "name": "future"
+        }));
+        match &filter {
+            CharFilter::Unknown(value) => assert_eq!(
+                value["@odata.type"],
+                "#Microsoft.Azure.Search.FutureCharFilter"
+            ),
+            other => panic!("{:?}", other),
+        }
+        assert_eq!(filter.name(), Some("future"));
+    }
+}
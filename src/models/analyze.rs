@@ -0,0 +1,137 @@
+use serde::{Deserialize, Serialize};
+
+/// Analyze request parameters
+///
+/// Exactly one of `analyzer`, `tokenizer` or `normalizer` must be set; token
+/// and char filters can only be combined with a tokenizer.
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct AnalyzeRequest {
+    /// Text to break into tokens
+    pub text: String,
+    /// Analyzer to apply
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub analyzer: Option<String>,
+    /// Tokenizer to apply
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tokenizer: Option<String>,
+    /// Normalizer to apply
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub normalizer: Option<String>,
+    /// Token filters applied after the tokenizer
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub token_filters: Option<Vec<String>>,
+    /// Char filters applied before the tokenizer
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub char_filters: Option<Vec<String>>,
+}
+
+impl AnalyzeRequest {
+    /// Analyze text with an analyzer
+    pub fn analyzer(text: impl Into<String>, analyzer: impl Into<String>) -> Self {
+        Self {
+            text: text.into(),
+            analyzer: Some(analyzer.into()),
+            ..Default::default()
+        }
+    }
+
+    /// Analyze text with a tokenizer and optional filters
+    pub fn tokenizer(text: impl Into<String>, tokenizer: impl Into<String>) -> Self {
+        Self {
+            text: text.into(),
+            tokenizer: Some(tokenizer.into()),
+            ..Default::default()
+        }
+    }
+
+    /// Analyze text with a normalizer
+    pub fn normalizer(text: impl Into<String>, normalizer: impl Into<String>) -> Self {
+        Self {
+            text: text.into(),
+            normalizer: Some(normalizer.into()),
+            ..Default::default()
+        }
+    }
+
+    /// Add a token filter applied after the tokenizer
+    pub fn token_filter(mut self, token_filter: impl Into<String>) -> Self {
+        self.token_filters
+            .get_or_insert_with(Vec::new)
+            .push(token_filter.into());
+        self
+    }
+
+    /// Add a char filter applied before the tokenizer
+    pub fn char_filter(mut self, char_filter: impl Into<String>) -> Self {
+        self.char_filters
+            .get_or_insert_with(Vec::new)
+            .push(char_filter.into());
+        self
+    }
+}
+
+/// Token produced by an analyzer
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct AnalyzedToken {
+    /// Token text
+    pub token: String,
+    /// Offset of the first character of the token in the input text
+    pub start_offset: usize,
+    /// Offset after the last character of the token in the input text
+    pub end_offset: usize,
+    /// Position of the token relative to the other tokens
+    pub position: usize,
+}
+
+/// Analyze response
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct AnalyzeResult {
+    /// Tokens produced from the text
+    pub tokens: Vec<AnalyzedToken>,
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn request_serializes_tokenizer_and_filters() {
+        let request = AnalyzeRequest::tokenizer("The Quick Fox", "standard_v2")
+            .token_filter("lowercase")
+            .char_filter("html_strip");
+        assert_eq!(
+            serde_json::to_value(&request).unwrap(),
+            json!({
+                "text": "The Quick Fox",
+                "tokenizer": "standard_v2",
+                "tokenFilters": ["lowercase"],
+                "charFilters": ["html_strip"]
+            })
+        );
+        assert_eq!(
+            serde_json::to_value(AnalyzeRequest::analyzer("text", "en.lucene")).unwrap(),
+            json!({ "text": "text", "analyzer": "en.lucene" })
+        );
+    }
+
+    #[test]
+    fn result_parses_tokens() {
+        let result: AnalyzeResult = serde_json::from_value(json!({
+            "tokens": [{ "token": "quick", "startOffset": 4, "endOffset": 9, "position": 1 }]
+        }))
+        .unwrap();
+        assert_eq!(
+            result.tokens,
+            [AnalyzedToken {
+                token: "quick".to_string(),
+                start_offset: 4,
+                end_offset: 9,
+                position: 1,
+            }]
+        );
+    }
+}
@@ -0,0 +1,117 @@
+use serde::{Deserialize, Serialize};
+
+use super::search::{deserialize_option_vec_from_string, serialize_option_vec_as_string};
+
+/// How autocomplete terms are produced
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(rename_all = "camelCase")]
+pub enum AutocompleteMode {
+    /// Complete only the last term
+    #[default]
+    OneTerm,
+    /// Complete the last term and suggest the following term
+    TwoTerms,
+    /// Complete the last term using the preceding terms as context
+    OneTermWithContext,
+}
+
+/// Autocomplete request parameters
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct AutocompleteRequest {
+    /// Incomplete term to complete
+    pub search: String,
+    /// Name of the suggester defined in the index
+    pub suggester_name: String,
+    /// Autocomplete mode
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub autocomplete_mode: Option<AutocompleteMode>,
+    /// Use fuzzy matching
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fuzzy: Option<bool>,
+    /// Filter expression
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub filter: Option<String>,
+    /// Highlight pre/post tags
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub highlight_post_tag: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub highlight_pre_tag: Option<String>,
+    /// % of index that must be covered to declare query successful
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub minimum_coverage: Option<u8>,
+    /// Search fields (must be part of the suggester)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(serialize_with = "serialize_option_vec_as_string")]
+    #[serde(default, deserialize_with = "deserialize_option_vec_from_string")]
+    pub search_fields: Option<Vec<String>>,
+    /// Number of completions to return (1 to 100, default 5)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub top: Option<u8>,
+}
+
+impl AutocompleteRequest {
+    /// Create a request for the given text and suggester
+    pub fn new(search: impl Into<String>, suggester_name: impl Into<String>) -> Self {
+        Self {
+            search: search.into(),
+            suggester_name: suggester_name.into(),
+            ..Default::default()
+        }
+    }
+}
+
+/// Autocomplete item
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct AutocompleteItem {
+    /// Completed term
+    pub text: String,
+    /// Query with the completed term
+    pub query_plus_text: String,
+}
+
+/// Autocomplete response
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct AutocompleteResponse {
+    /// Completions
+    pub value: Vec<AutocompleteItem>,
+    /// % of the index covered by the query, if minimum coverage was requested
+    #[serde(rename = "@search.coverage", skip_serializing_if = "Option::is_none")]
+    pub coverage: Option<f64>,
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn request_serializes_mode_and_fields() {
+        let request = AutocompleteRequest {
+            autocomplete_mode: Some(AutocompleteMode::OneTermWithContext),
+            search_fields: Some(vec!["name".to_string(), "city".to_string()]),
+            ..AutocompleteRequest::new("washington medic", "sg")
+        };
+        assert_eq!(
+            serde_json::to_value(&request).unwrap(),
+            json!({
+                "search": "washington medic",
+                "suggesterName": "sg",
+                "autocompleteMode": "oneTermWithContext",
+                "searchFields": "name,city"
+            })
+        );
+    }
+
+    #[test]
+    fn response_parses() {
+        let response: AutocompleteResponse = serde_json::from_value(json!({
+            "value": [{ "text": "medicine", "queryPlusText": "washington medicine" }]
+        }))
+        .unwrap();
+        assert_eq!(response.value[0].query_plus_text, "washington medicine");
+        assert!(response.coverage.is_none());
+    }
+}
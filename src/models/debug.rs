@@ -0,0 +1,239 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+/// Debugging information to return with the results (preview)
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum QueryDebugMode {
+    /// No debugging information
+    Disabled,
+    /// Semantic ranker inputs
+    Semantic,
+    /// Text and vector subscores of hybrid queries
+    Vector,
+    /// Generated query rewrites
+    QueryRewrites,
+    /// Every kind of debugging information
+    All,
+}
+
+/// Whether to return per-field `@search.features` with each result (preview)
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum FeaturesMode {
+    /// disabled
+    Disabled,
+    /// enabled
+    Enabled,
+}
+
+/// Ranking features of one searched field
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct FieldFeatures {
+    /// Number of distinct query terms found in the field
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unique_token_matches: Option<f64>,
+    /// Similarity of the field content to the query
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub similarity_score: Option<f64>,
+    /// Number of times the query terms occur in the field
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub term_frequency: Option<f64>,
+}
+
+/// Query-level debugging information (`@search.debug`)
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct SearchDebugInfo {
+    /// Rewrites generated for the text and vector queries
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub query_rewrites: Option<QueryRewritesDebugInfo>,
+}
+
+/// Query rewrites generated for a request
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct QueryRewritesDebugInfo {
+    /// Rewrites of the text query
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub text: Option<QueryRewrites>,
+    /// Rewrites of each vectorizable text query
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub vectors: Option<Vec<QueryRewrites>>,
+}
+
+/// Input query and the rewrites generated from it
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct QueryRewrites {
+    /// Query the rewrites were generated from
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub input_query: Option<String>,
+    /// Generated rewrites
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rewrites: Option<Vec<String>>,
+}
+
+/// Document-level debugging information (`@search.documentDebugInfo`)
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct DocumentDebugInfo {
+    /// Inputs of the semantic ranker
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub semantic: Option<SemanticDebugInfo>,
+    /// Subscores of a hybrid query
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub vectors: Option<VectorsDebugInfo>,
+}
+
+/// Fields and text the semantic ranker used for a document
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct SemanticDebugInfo {
+    /// Title field
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title_field: Option<SemanticFieldDebugInfo>,
+    /// Content fields
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub content_fields: Option<Vec<SemanticFieldDebugInfo>>,
+    /// Keyword fields
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub keyword_fields: Option<Vec<SemanticFieldDebugInfo>>,
+    /// Text passed to the reranker
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reranker_input: Option<RerankerInput>,
+}
+
+/// Semantic configuration field and whether it was used
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct SemanticFieldDebugInfo {
+    /// Field name
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// `used`, `unused` or `partial`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub state: Option<String>,
+}
+
+/// Concatenated text passed to the semantic reranker
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct RerankerInput {
+    /// Title text
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    /// Content text
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub content: Option<String>,
+    /// Keyword text
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub keywords: Option<String>,
+}
+
+/// Vector debugging information of a document
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct VectorsDebugInfo {
+    /// Breakdown of the document score
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subscores: Option<DocumentSubscores>,
+}
+
+/// Breakdown of a hybrid document score
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct DocumentSubscores {
+    /// Score of the text query
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub text: Option<TextSubscore>,
+    /// Scores of each vector query, keyed by field name
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub vectors: Option<Vec<HashMap<String, VectorSubscore>>>,
+    /// Boost applied by the scoring profile
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub document_boost: Option<f64>,
+}
+
+/// Score of the text part of a hybrid query
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct TextSubscore {
+    /// BM25 score
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub search_score: Option<f64>,
+}
+
+/// Score of a vector query against one field
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct VectorSubscore {
+    /// Score contributed to `@search.score`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub search_score: Option<f64>,
+    /// Similarity in the field's metric
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub vector_similarity: Option<f64>,
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn modes_serialize_in_camel_case() {
+        assert_eq!(
+            serde_json::to_value(QueryDebugMode::QueryRewrites).unwrap(),
+            json!("queryRewrites")
+        );
+        assert_eq!(
+            serde_json::to_value(FeaturesMode::Enabled).unwrap(),
+            json!("enabled")
+        );
+    }
+
+    #[test]
+    fn document_debug_info_parses_hybrid_subscores() {
+        let info: DocumentDebugInfo = serde_json::from_value(json!({
+            "semantic": {
+                "titleField": { "name": "title", "state": "used" },
+                "contentFields": [{ "name": "description", "state": "partial" }],
+                "rerankerInput": { "title": "Hotel", "content": "Quiet rooms" }
+            },
+            "vectors": {
+                "subscores": {
+                    "text": { "searchScore": 1.5 },
+                    "vectors": [{ "embedding": { "searchScore": 0.8, "vectorSimilarity": 0.75 } }],
+                    "documentBoost": 2.0
+                }
+            }
+        }))
+        .unwrap();
+        let semantic = info.semantic.unwrap();
+        assert_eq!(semantic.title_field.unwrap().state.unwrap(), "used");
+        assert_eq!(
+            semantic.reranker_input.unwrap().content.unwrap(),
+            "Quiet rooms"
+        );
+        let subscores = info.vectors.unwrap().subscores.unwrap();
+        assert_eq!(subscores.text.unwrap().search_score, Some(1.5));
+        assert_eq!(
+            subscores.vectors.unwrap()[0]["embedding"].vector_similarity,
+            Some(0.75)
+        );
+        assert_eq!(subscores.document_boost, Some(2.0));
+    }
+
+    #[test]
+    fn field_features_omit_missing_values() {
+        let features = FieldFeatures {
+            term_frequency: Some(2.0),
+            ..Default::default()
+        };
+        assert_eq!(
+            serde_json::to_value(&features).unwrap(),
+            json!({ "termFrequency": 2.0 })
+        );
+    }
+}
@@ -0,0 +1,342 @@
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+/// Operation applied to a document in an index batch
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum IndexActionType {
+    /// Insert the document, replacing it entirely if it already exists
+    Upload,
+    /// Update the given fields of an existing document
+    Merge,
+    /// Merge into an existing document, or upload it if missing
+    MergeOrUpload,
+    /// Remove the document; only the key field is required
+    Delete,
+}
+
+/// A document together with the action to apply to it
+///
+/// For merge actions, `T` is usually a partial document: a struct whose
+/// optional fields are annotated with
+/// `#[serde(skip_serializing_if = "Option::is_none")]`, so that unset fields
+/// are omitted and left untouched in the index.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct IndexAction<T> {
+    /// Action to apply
+    #[serde(rename = "@search.action")]
+    pub action: IndexActionType,
+    /// The (possibly partial) document
+    #[serde(flatten)]
+    pub document: T,
+}
+
+impl<T> IndexAction<T> {
+    /// Create a new action
+    pub fn new(action: IndexActionType, document: T) -> Self {
+        Self { action, document }
+    }
+
+    /// Upload a document
+    pub fn upload(document: T) -> Self {
+        Self::new(IndexActionType::Upload, document)
+    }
+
+    /// Merge a (partial) document
+    pub fn merge(document: T) -> Self {
+        Self::new(IndexActionType::Merge, document)
+    }
+
+    /// Merge a (partial) document, or upload it if it doesn't exist
+    pub fn merge_or_upload(document: T) -> Self {
+        Self::new(IndexActionType::MergeOrUpload, document)
+    }
+
+    /// Delete a document
+    pub fn delete(document: T) -> Self {
+        Self::new(IndexActionType::Delete, document)
+    }
+}
+
+impl IndexAction<Value> {
+    /// Delete a document by its key
+    pub fn delete_key(key_field: &str, key: impl Into<String>) -> Self {
+        let mut document = Map::new();
+        document.insert(key_field.to_string(), Value::String(key.into()));
+        Self::delete(Value::Object(document))
+    }
+}
+
+/// Batch of index actions sent to `/docs/index`
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct IndexBatch<T> {
+    /// Actions in the batch
+    pub value: Vec<IndexAction<T>>,
+}
+
+impl<T> Default for IndexBatch<T> {
+    fn default() -> Self {
+        Self { value: Vec::new() }
+    }
+}
+
+impl<T> IndexBatch<T> {
+    /// Create an empty batch
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a batch applying the same action to every document
+    pub fn with_action(action: IndexActionType, documents: impl IntoIterator<Item = T>) -> Self {
+        Self {
+            value: documents
+                .into_iter()
+                .map(|document| IndexAction::new(action, document))
+                .collect(),
+        }
+    }
+
+    /// Create a batch uploading every document
+    pub fn upload(documents: impl IntoIterator<Item = T>) -> Self {
+        Self::with_action(IndexActionType::Upload, documents)
+    }
+
+    /// Create a batch merging every document
+    pub fn merge(documents: impl IntoIterator<Item = T>) -> Self {
+        Self::with_action(IndexActionType::Merge, documents)
+    }
+
+    /// Create a batch merging or uploading every document
+    pub fn merge_or_upload(documents: impl IntoIterator<Item = T>) -> Self {
+        Self::with_action(IndexActionType::MergeOrUpload, documents)
+    }
+
+    /// Create a batch deleting every document
+    pub fn delete(documents: impl IntoIterator<Item = T>) -> Self {
+        Self::with_action(IndexActionType::Delete, documents)
+    }
+
+    /// Append an action
+    pub fn push(&mut self, action: IndexAction<T>) {
+        self.value.push(action);
+    }
+
+    /// Number of actions in the batch
+    pub fn len(&self) -> usize {
+        self.value.len()
+    }
+
+    /// Whether the batch has no actions
+    pub fn is_empty(&self) -> bool {
+        self.value.is_empty()
+    }
+}
+
+impl IndexBatch<Value> {
+    /// Create a batch deleting documents by key
+    pub fn delete_keys<K: Into<String>>(
+        key_field: &str,
+        keys: impl IntoIterator<Item = K>,
+    ) -> Self {
+        Self {
+            value: keys
+                .into_iter()
+                .map(|key| IndexAction::delete_key(key_field, key))
+                .collect(),
+        }
+    }
+}
+
+impl<T> FromIterator<IndexAction<T>> for IndexBatch<T> {
+    fn from_iter<I: IntoIterator<Item = IndexAction<T>>>(iter: I) -> Self {
+        Self {
+            value: iter.into_iter().collect(),
+        }
+    }
+}
+
+/// Status of a single document in an index batch
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct IndexingResult {
+    /// Key of the document
+    pub key: String,
+    /// Whether the action succeeded
+    pub status: bool,
+    /// Error message, if the action failed
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error_message: Option<String>,
+    /// HTTP status code for this document (200, 201, 400, 404, 409, 422, 503, ...)
+    pub status_code: u16,
+}
+
+impl IndexingResult {
+    /// Whether the failure is transient and the action may succeed if resent
+    pub fn is_retriable(&self) -> bool {
+        !self.status && matches!(self.status_code, 409 | 422 | 503)
+    }
+}
+
+/// Response of `/docs/index`, with one entry per action in the batch
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct IndexDocumentsResult {
+    /// Per-document results
+    pub value: Vec<IndexingResult>,
+}
+
+impl IndexDocumentsResult {
+    /// Results of actions that succeeded
+    pub fn succeeded(&self) -> impl Iterator<Item = &IndexingResult> {
+        self.value.iter().filter(|result| result.status)
+    }
+
+    /// Results of actions that failed
+    pub fn failed(&self) -> impl Iterator<Item = &IndexingResult> {
+        self.value.iter().filter(|result| !result.status)
+    }
+
+    /// Keys of documents whose actions failed
+    pub fn failed_keys(&self) -> Vec<&str> {
+        self.failed().map(|result| result.key.as_str()).collect()
+    }
+
+    /// Whether every action succeeded
+    pub fn is_success(&self) -> bool {
+        self.value.iter().all(|result| result.status)
+    }
+}
+
+/// Maximum number of actions the service accepts in one batch
+pub const MAX_BATCH_SIZE: usize = 1000;
+
+/// Maximum payload size the service accepts for one batch (16 MB)
+pub const MAX_BATCH_BYTES: usize = 16 * 1024 * 1024;
+
+/// Options for sending an index batch
+#[derive(Debug, Clone)]
+pub struct IndexDocumentsOptions {
+    /// Return [`crate::Error::IndexingFailed`] if any document fails,
+    /// instead of reporting failures only in the returned result
+    pub throw_on_any_failure: bool,
+    /// Maximum number of actions per request
+    pub max_batch_size: usize,
+    /// Maximum serialized size of a request body in bytes
+    pub max_batch_bytes: usize,
+    /// Number of requests sent concurrently when the input is split
+    pub max_concurrency: usize,
+}
+
+impl Default for IndexDocumentsOptions {
+    fn default() -> Self {
+        Self {
+            throw_on_any_failure: false,
+            max_batch_size: MAX_BATCH_SIZE,
+            max_batch_bytes: MAX_BATCH_BYTES,
+            max_concurrency: 1,
+        }
+    }
+}
+
+impl IndexDocumentsOptions {
+    /// Fail the call if any document fails
+    pub fn throw_on_any_failure(mut self, throw_on_any_failure: bool) -> Self {
+        self.throw_on_any_failure = throw_on_any_failure;
+        self
+    }
+
+    /// Set the maximum number of actions per request
+    pub fn max_batch_size(mut self, max_batch_size: usize) -> Self {
+        self.max_batch_size = max_batch_size;
+        self
+    }
+
+    /// Set the maximum serialized size of a request body in bytes
+    pub fn max_batch_bytes(mut self, max_batch_bytes: usize) -> Self {
+        self.max_batch_bytes = max_batch_bytes;
+        self
+    }
+
+    /// Set the number of requests sent concurrently
+    pub fn max_concurrency(mut self, max_concurrency: usize) -> Self {
+        self.max_concurrency = max_concurrency;
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[derive(Debug, Serialize, Deserialize, PartialEq)]
+    struct Hotel {
+        id: String,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        rating: Option<u8>,
+    }
+
+    #[test]
+    fn actions_are_flattened_into_the_document() {
+        let batch: IndexBatch<Hotel> = [
+            IndexAction::upload(Hotel {
+                id: "1".to_string(),
+                rating: Some(4),
+            }),
+            IndexAction::merge(Hotel {
+                id: "2".to_string(),
+                rating: None,
+            }),
+        ]
+        .into_iter()
+        .collect();
+        let expected = json!({
+            "value": [
+                { "@search.action": "upload", "id": "1", "rating": 4 },
+                { "@search.action": "merge", "id": "2" }
+            ]
+        });
+        assert_eq!(serde_json::to_value(&batch).unwrap(), expected);
+
+        let parsed: IndexBatch<Hotel> = serde_json::from_value(expected).unwrap();
+        assert_eq!(parsed.value[1].action, IndexActionType::Merge);
+        assert_eq!(parsed.value[1].document.rating, None);
+    }
+
+    #[test]
+    fn delete_by_key() {
+        let batch = IndexBatch::delete_keys("id", ["1", "2"]);
+        assert_eq!(batch.len(), 2);
+        assert_eq!(
+            serde_json::to_value(&batch.value[0]).unwrap(),
+            json!({ "@search.action": "delete", "id": "1" })
+        );
+        let batch = IndexBatch::merge_or_upload([json!({ "id": "3" })]);
+        assert_eq!(
+            serde_json::to_value(&batch).unwrap()["value"][0]["@search.action"],
+            "mergeOrUpload"
+        );
+        assert!(IndexBatch::<Value>::new().is_empty());
+    }
+
+    #[test]
+    fn results_report_failures() {
+        let result: IndexDocumentsResult = serde_json::from_value(json!({
+            "value": [
+                { "key": "1", "status": true, "errorMessage": null, "statusCode": 201 },
+                { "key": "2", "status": false, "errorMessage": "busy", "statusCode": 503 },
+                { "key": "3", "status": false, "errorMessage": "bad", "statusCode": 400 }
+            ]
+        }))
+        .unwrap();
+        assert!(!result.is_success());
+        assert_eq!(result.succeeded().count(), 1);
+        assert_eq!(result.failed_keys(), ["2", "3"]);
+        let retriable: Vec<bool> = result
+            .value
+            .iter()
+            .map(IndexingResult::is_retriable)
+            .collect();
+        assert_eq!(retriable, [false, true, false]);
+    }
+}
@@ -0,0 +1,508 @@
+use std::collections::HashMap;
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+use crate::error::ValidationError;
+
+/// Characters that separate parameters, range values and nested facets; the
+/// facet syntax has no way to escape them
+const SEPARATORS: &[char] = &[',', '|', ';', '(', ')', '>'];
+
+/// Order of facet buckets
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FacetSort {
+    /// Most frequent values first (`count`)
+    CountDescending,
+    /// Least frequent values first (`-count`)
+    CountAscending,
+    /// Values in ascending order (`value`)
+    ValueAscending,
+    /// Values in descending order (`-value`)
+    ValueDescending,
+}
+
+impl FacetSort {
+    fn as_str(&self) -> &'static str {
+        match self {
+            FacetSort::CountDescending => "count",
+            FacetSort::CountAscending => "-count",
+            FacetSort::ValueAscending => "value",
+            FacetSort::ValueDescending => "-value",
+        }
+    }
+}
+
+/// Bucket width for interval facets
+#[derive(Debug, Clone, PartialEq)]
+pub enum FacetInterval {
+    /// Numeric interval
+    Number(f64),
+    /// minute
+    Minute,
+    /// hour
+    Hour,
+    /// day
+    Day,
+    /// week
+    Week,
+    /// month
+    Month,
+    /// quarter
+    Quarter,
+    /// year
+    Year,
+}
+
+impl fmt::Display for FacetInterval {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FacetInterval::Number(interval) => write!(f, "{}", interval),
+            FacetInterval::Minute => f.write_str("minute"),
+            FacetInterval::Hour => f.write_str("hour"),
+            FacetInterval::Day => f.write_str("day"),
+            FacetInterval::Week => f.write_str("week"),
+            FacetInterval::Month => f.write_str("month"),
+            FacetInterval::Quarter => f.write_str("quarter"),
+            FacetInterval::Year => f.write_str("year"),
+        }
+    }
+}
+
+/// Aggregation computed by a metric facet
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FacetMetric {
+    /// Sum of the field values
+    Sum,
+    /// Minimum field value
+    Min,
+    /// Maximum field value
+    Max,
+    /// Average field value
+    Avg,
+    /// Approximate number of distinct values
+    Cardinality,
+}
+
+impl FacetMetric {
+    fn as_str(&self) -> &'static str {
+        match self {
+            FacetMetric::Sum => "sum",
+            FacetMetric::Min => "min",
+            FacetMetric::Max => "max",
+            FacetMetric::Avg => "avg",
+            FacetMetric::Cardinality => "cardinality",
+        }
+    }
+
+    const ALL: [FacetMetric; 5] = [
+        FacetMetric::Sum,
+        FacetMetric::Min,
+        FacetMetric::Max,
+        FacetMetric::Avg,
+        FacetMetric::Cardinality,
+    ];
+}
+
+/// Facet expression, rendered into the strings sent in `SearchRequest::facets`
+///
+/// Parameter values are rendered verbatim; call [`FacetSpec::validate`] on
+/// specs built from user input, or add them with
+/// [`SearchRequestBuilder::facet_spec`](super::search::SearchRequestBuilder::facet_spec).
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct FacetSpec {
+    field: String,
+    count: Option<u32>,
+    sort: Option<FacetSort>,
+    values: Option<Vec<String>>,
+    interval: Option<FacetInterval>,
+    timeoffset: Option<String>,
+    metric: Option<FacetMetric>,
+    default: Option<String>,
+    nested: Vec<FacetSpec>,
+}
+
+impl FacetSpec {
+    /// Facet on a field
+    pub fn new(field: impl Into<String>) -> Self {
+        Self {
+            field: field.into(),
+            ..Default::default()
+        }
+    }
+
+    /// Maximum number of value buckets to return
+    pub fn count(mut self, count: u32) -> Self {
+        self.count = Some(count);
+        self
+    }
+
+    /// Order of the buckets
+    pub fn sort(mut self, sort: FacetSort) -> Self {
+        self.sort = Some(sort);
+        self
+    }
+
+    /// Range buckets bounded by the given values, in ascending order
+    pub fn values<I, S>(mut self, values: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: ToString,
+    {
+        self.values = Some(values.into_iter().map(|value| value.to_string()).collect());
+        self
+    }
+
+    /// Buckets of a fixed numeric or date interval
+    pub fn interval(mut self, interval: FacetInterval) -> Self {
+        self.interval = Some(interval);
+        self
+    }
+
+    /// UTC offset applied to date interval boundaries (e.g. `+01:00`)
+    pub fn timeoffset(mut self, timeoffset: impl Into<String>) -> Self {
+        self.timeoffset = Some(timeoffset.into());
+        self
+    }
+
+    /// Aggregate the field instead of bucketing it
+    pub fn metric(mut self, metric: FacetMetric) -> Self {
+        self.metric = Some(metric);
+        self
+    }
+
+    /// Value used for documents missing the field in a metric facet
+    pub fn default_value(mut self, default: impl ToString) -> Self {
+        self.default = Some(default.to_string());
+        self
+    }
+
+    /// Compute a facet inside each bucket of this one
+    pub fn nested(mut self, facet: FacetSpec) -> Self {
+        self.nested.push(facet);
+        self
+    }
+
+    /// Check that no parameter value, here or in a nested facet, contains a
+    /// separator that would change the meaning of the rendered expression
+    pub fn validate(&self) -> Result<(), ValidationError> {
+        let check = |parameter: &'static str, value: &str| {
+            if value.contains(SEPARATORS) {
+                Err(ValidationError::InvalidFacetParameter {
+                    field: self.field.clone(),
+                    parameter,
+                    value: value.to_string(),
+                })
+            } else {
+                Ok(())
+            }
+        };
+        for value in self.values.iter().flatten() {
+            check("values", value)?;
+        }
+        if let Some(timeoffset) = &self.timeoffset {
+            check("timeoffset", timeoffset)?;
+        }
+        if let Some(default) = &self.default {
+            check("default", default)?;
+        }
+        self.nested.iter().try_for_each(FacetSpec::validate)
+    }
+}
+
+impl fmt::Display for FacetSpec {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.field)?;
+        if let Some(count) = self.count {
+            write!(f, ",count:{}", count)?;
+        }
+        if let Some(sort) = self.sort {
+            write!(f, ",sort:{}", sort.as_str())?;
+        }
+        if let Some(values) = &self.values {
+            write!(f, ",values:{}", values.join("|"))?;
+        }
+        if let Some(interval) = &self.interval {
+            write!(f, ",interval:{}", interval)?;
+        }
+        if let Some(timeoffset) = &self.timeoffset {
+            write!(f, ",timeoffset:{}", timeoffset)?;
+        }
+        if let Some(metric) = self.metric {
+            write!(f, ",metric:{}", metric.as_str())?;
+        }
+        if let Some(default) = &self.default {
+            write!(f, ",default:{}", default)?;
+        }
+        match self.nested.as_slice() {
+            [] => Ok(()),
+            [nested] => write!(f, " > {}", nested),
+            nested => {
+                f.write_str(" > (")?;
+                for (position, facet) in nested.iter().enumerate() {
+                    if position > 0 {
+                        f.write_str("; ")?;
+                    }
+                    write!(f, "{}", facet)?;
+                }
+                f.write_str(")")
+            }
+        }
+    }
+}
+
+impl From<FacetSpec> for String {
+    fn from(facet: FacetSpec) -> Self {
+        facet.to_string()
+    }
+}
+
+/// Facet results keyed by field name
+pub type Facets = HashMap<String, Vec<FacetResult>>;
+
+/// Single facet bucket or aggregation
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(from = "RawFacetResult", into = "RawFacetResult")]
+pub enum FacetResult {
+    /// Documents sharing a value
+    Value {
+        value: Value,
+        count: Option<u64>,
+        facets: Option<Facets>,
+    },
+    /// Documents within `[from, to)`; an open bound is `None`
+    Range {
+        from: Option<Value>,
+        to: Option<Value>,
+        count: Option<u64>,
+        facets: Option<Facets>,
+    },
+    /// Aggregated value of a metric facet
+    Metric { metric: FacetMetric, value: f64 },
+}
+
+impl FacetResult {
+    /// Number of documents in the bucket
+    pub fn count(&self) -> Option<u64> {
+        match self {
+            FacetResult::Value { count, .. } | FacetResult::Range { count, .. } => *count,
+            FacetResult::Metric { .. } => None,
+        }
+    }
+
+    /// Facets computed inside the bucket
+    pub fn facets(&self) -> Option<&Facets> {
+        match self {
+            FacetResult::Value { facets, .. } | FacetResult::Range { facets, .. } => {
+                facets.as_ref()
+            }
+            FacetResult::Metric { .. } => None,
+        }
+    }
+}
+
+/// Wire format shared by every kind of facet result
+#[derive(Serialize, Deserialize, Default)]
+struct RawFacetResult {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    count: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    value: Option<Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    from: Option<Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    to: Option<Value>,
+    #[serde(flatten)]
+    metrics: Map<String, Value>,
+    #[serde(rename = "@search.facets", skip_serializing_if = "Option::is_none")]
+    facets: Option<Facets>,
+}
+
+impl From<RawFacetResult> for FacetResult {
+    fn from(raw: RawFacetResult) -> Self {
+        if raw.value.is_none() && (raw.from.is_some() || raw.to.is_some()) {
+            return FacetResult::Range {
+                from: raw.from,
+                to: raw.to,
+                count: raw.count,
+                facets: raw.facets,
+            };
+        }
+        if raw.value.is_none() {
+            if let Some((metric, value)) = FacetMetric::ALL
+                .iter()
+                .find_map(|metric| Some((*metric, raw.metrics.get(metric.as_str())?.as_f64()?)))
+            {
+                return FacetResult::Metric { metric, value };
+            }
+        }
+        FacetResult::Value {
+            value: raw.value.unwrap_or(Value::Null),
+            count: raw.count,
+            facets: raw.facets,
+        }
+    }
+}
+
+impl From<FacetResult> for RawFacetResult {
+    fn from(result: FacetResult) -> Self {
+        match result {
+            FacetResult::Value {
+                value,
+                count,
+                facets,
+            } => RawFacetResult {
+                count,
+                value: Some(value),
+                facets,
+                ..Default::default()
+            },
+            FacetResult::Range {
+                from,
+                to,
+                count,
+                facets,
+            } => RawFacetResult {
+                count,
+                from,
+                to,
+                facets,
+                ..Default::default()
+            },
+            FacetResult::Metric { metric, value } => RawFacetResult {
+                metrics: Map::from_iter([(metric.as_str().to_string(), Value::from(value))]),
+                ..Default::default()
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::models::search::SearchRequest;
+
+    #[test]
+    fn renders_parameters_and_nested_facets() {
+        let facet = FacetSpec::new("rating")
+            .count(5)
+            .sort(FacetSort::ValueDescending)
+            .nested(
+                FacetSpec::new("price")
+                    .metric(FacetMetric::Avg)
+                    .default_value(0),
+            )
+            .nested(FacetSpec::new("tags").count(3));
+        assert_eq!(
+            facet.to_string(),
+            "rating,count:5,sort:-value > (price,metric:avg,default:0; tags,count:3)"
+        );
+        assert_eq!(facet.validate(), Ok(()));
+
+        let facet = FacetSpec::new("lastRenovated")
+            .interval(FacetInterval::Month)
+            .timeoffset("-05:00");
+        assert_eq!(
+            String::from(facet),
+            "lastRenovated,interval:month,timeoffset:-05:00"
+        );
+        assert_eq!(
+            FacetSpec::new("price").values([10, 20]).to_string(),
+            "price,values:10|20"
+        );
+        assert_eq!(
+            FacetSpec::new("price")
+                .interval(FacetInterval::Number(2.5))
+                .to_string(),
+            "price,interval:2.5"
+        );
+    }
+
+    #[test]
+    fn separators_in_parameters_are_rejected() {
+        let cases = [
+            (FacetSpec::new("price").values(["10,count:1"]), "values"),
+            (FacetSpec::new("price").values(["10|20", "30"]), "values"),
+            (
+                FacetSpec::new("date").timeoffset("+01:00,count:9"),
+                "timeoffset",
+            ),
+            (FacetSpec::new("price").default_value("1; tags"), "default"),
+            (FacetSpec::new("price").default_value("0) > (x"), "default"),
+        ];
+        for (facet, parameter) in cases {
+            match facet.validate() {
+                Err(ValidationError::InvalidFacetParameter {
+                    field,
+                    parameter: rejected,
+                    ..
+                }) => {
+                    assert_eq!(rejected, parameter);
+                    assert!(facet.to_string().starts_with(&field));
+                }
+                other => panic!("{}: {:?}", facet, other),
+            }
+        }
+
+        let nested = FacetSpec::new("rating").nested(FacetSpec::new("price").values(["1|2"]));
+        assert!(matches!(
+            nested.validate(),
+            Err(ValidationError::InvalidFacetParameter { field, .. }) if field == "price"
+        ));
+    }
+
+    #[test]
+    fn builder_validates_facet_specs() {
+        let request = SearchRequest::builder()
+            .facet("category,count:10")
+            .facet_spec(FacetSpec::new("rating").values([2, 4]))
+            .build()
+            .unwrap();
+        assert_eq!(
+            request.facets,
+            Some(vec![
+                "category,count:10".to_string(),
+                "rating,values:2|4".to_string()
+            ])
+        );
+
+        let result = SearchRequest::builder()
+            .facet_spec(FacetSpec::new("rating").values(["2,count:1000"]))
+            .build();
+        assert!(matches!(
+            result,
+            Err(ValidationError::InvalidFacetParameter { .. })
+        ));
+    }
+
+    #[test]
+    fn facet_results_round_trip() {
+        let raw = json!([
+            { "value": "Budget", "count": 3, "@search.facets": { "rating": [{ "sum": 12.5 }] } },
+            { "from": 10, "to": 20, "count": 4 },
+            { "to": 10, "count": 1 },
+            { "avg": 3.5 }
+        ]);
+        let results: Vec<FacetResult> = serde_json::from_value(raw.clone()).unwrap();
+        assert_eq!(results[0].count(), Some(3));
+        assert_eq!(
+            results[0].facets().unwrap()["rating"],
+            vec![FacetResult::Metric {
+                metric: FacetMetric::Sum,
+                value: 12.5
+            }]
+        );
+        assert!(matches!(results[2], FacetResult::Range { from: None, .. }));
+        assert_eq!(
+            results[3],
+            FacetResult::Metric {
+                metric: FacetMetric::Avg,
+                value: 3.5
+            }
+        );
+        assert_eq!(serde_json::to_value(&results).unwrap(), raw);
+    }
+}
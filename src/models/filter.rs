@@ -0,0 +1,958 @@
+//! Typed OData `$filter` expressions
+//!
+//! Field paths are written by the developer and rendered verbatim, while every
+//! value is rendered as a properly quoted and escaped OData literal, so user
+//! input can never change the structure of the expression.
+
+use std::fmt;
+use std::str::FromStr;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use thiserror::Error;
+
+use super::search::{QueryType, SearchMode};
+
+/// Comparison operator
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ComparisonOperator {
+    /// eq
+    Eq,
+    /// ne
+    Ne,
+    /// gt
+    Gt,
+    /// ge
+    Ge,
+    /// lt
+    Lt,
+    /// le
+    Le,
+}
+
+impl ComparisonOperator {
+    fn as_str(&self) -> &'static str {
+        match self {
+            ComparisonOperator::Eq => "eq",
+            ComparisonOperator::Ne => "ne",
+            ComparisonOperator::Gt => "gt",
+            ComparisonOperator::Ge => "ge",
+            ComparisonOperator::Lt => "lt",
+            ComparisonOperator::Le => "le",
+        }
+    }
+}
+
+/// Geographic point in WGS 84 coordinates
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GeoPoint {
+    /// Longitude
+    pub longitude: f64,
+    /// Latitude
+    pub latitude: f64,
+}
+
+impl GeoPoint {
+    /// Create a point from longitude and latitude
+    pub fn new(longitude: f64, latitude: f64) -> Self {
+        Self {
+            longitude,
+            latitude,
+        }
+    }
+}
+
+/// `Edm.DateTimeOffset` value such as `2024-01-01T00:00:00Z`
+///
+/// Created from a [`SystemTime`], or parsed from an ISO 8601 string with a
+/// time and a `Z` or `±hh:mm` offset, so only well-formed timestamps can be
+/// rendered into a filter.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct DateTimeOffset(String);
+
+impl DateTimeOffset {
+    /// The timestamp in ISO 8601 format
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl fmt::Display for DateTimeOffset {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+/// Error returned when a string is not an ISO 8601 date-time offset
+#[derive(Debug, Error, Clone, PartialEq, Eq)]
+#[error("invalid ISO 8601 date-time offset: {0:?}")]
+pub struct InvalidDateTimeOffset(pub String);
+
+impl FromStr for DateTimeOffset {
+    type Err = InvalidDateTimeOffset;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if is_date_time_offset(s) {
+            Ok(Self(s.to_string()))
+        } else {
+            Err(InvalidDateTimeOffset(s.to_string()))
+        }
+    }
+}
+
+impl TryFrom<&str> for DateTimeOffset {
+    type Error = InvalidDateTimeOffset;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+impl From<SystemTime> for DateTimeOffset {
+    fn from(time: SystemTime) -> Self {
+        let (seconds, nanos) = match time.duration_since(UNIX_EPOCH) {
+            Ok(elapsed) => (elapsed.as_secs() as i64, elapsed.subsec_nanos()),
+            Err(error) => {
+                // Before the epoch: round down to whole seconds and count forward
+                let before = error.duration();
+                let seconds = before.as_secs() as i64 + i64::from(before.subsec_nanos() > 0);
+                let nanos = (Duration::from_secs(seconds as u64) - before).subsec_nanos();
+                (-seconds, nanos)
+            }
+        };
+        let (year, month, day) = civil_from_days(seconds.div_euclid(86_400));
+        let second_of_day = seconds.rem_euclid(86_400);
+        let mut value = format!(
+            "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}",
+            year,
+            month,
+            day,
+            second_of_day / 3_600,
+            second_of_day % 3_600 / 60,
+            second_of_day % 60
+        );
+        if nanos > 0 {
+            let fraction = format!("{:09}", nanos);
+            value.push('.');
+            value.push_str(fraction.trim_end_matches('0'));
+        }
+        value.push('Z');
+        Self(value)
+    }
+}
+
+/// Literal value in a filter expression
+#[derive(Debug, Clone, PartialEq)]
+pub enum FilterValue {
+    /// null
+    Null,
+    /// Boolean
+    Bool(bool),
+    /// Integer
+    Int(i64),
+    /// Floating point number
+    Double(f64),
+    /// String, rendered quoted and escaped
+    String(String),
+    /// Date-time offset, rendered unquoted
+    DateTime(DateTimeOffset),
+    /// Geography point
+    Point(GeoPoint),
+}
+
+impl fmt::Display for FilterValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FilterValue::Null => f.write_str("null"),
+            FilterValue::Bool(value) => write!(f, "{}", value),
+            FilterValue::Int(value) => write!(f, "{}", value),
+            FilterValue::Double(value) => write_double(f, *value),
+            FilterValue::String(value) => f.write_str(&quote_string(value)),
+            FilterValue::DateTime(value) => f.write_str(value.as_str()),
+            FilterValue::Point(point) => write_point(f, point),
+        }
+    }
+}
+
+impl From<&str> for FilterValue {
+    fn from(value: &str) -> Self {
+        FilterValue::String(value.to_string())
+    }
+}
+
+impl From<String> for FilterValue {
+    fn from(value: String) -> Self {
+        FilterValue::String(value)
+    }
+}
+
+impl From<&String> for FilterValue {
+    fn from(value: &String) -> Self {
+        FilterValue::String(value.clone())
+    }
+}
+
+impl From<bool> for FilterValue {
+    fn from(value: bool) -> Self {
+        FilterValue::Bool(value)
+    }
+}
+
+impl From<i32> for FilterValue {
+    fn from(value: i32) -> Self {
+        FilterValue::Int(value.into())
+    }
+}
+
+impl From<i64> for FilterValue {
+    fn from(value: i64) -> Self {
+        FilterValue::Int(value)
+    }
+}
+
+impl From<f32> for FilterValue {
+    fn from(value: f32) -> Self {
+        FilterValue::Double(value.into())
+    }
+}
+
+impl From<f64> for FilterValue {
+    fn from(value: f64) -> Self {
+        FilterValue::Double(value)
+    }
+}
+
+impl From<DateTimeOffset> for FilterValue {
+    fn from(value: DateTimeOffset) -> Self {
+        FilterValue::DateTime(value)
+    }
+}
+
+impl From<SystemTime> for FilterValue {
+    fn from(value: SystemTime) -> Self {
+        FilterValue::DateTime(value.into())
+    }
+}
+
+impl From<GeoPoint> for FilterValue {
+    fn from(value: GeoPoint) -> Self {
+        FilterValue::Point(value)
+    }
+}
+
+impl<T: Into<FilterValue>> From<Option<T>> for FilterValue {
+    fn from(value: Option<T>) -> Self {
+        value.map_or(FilterValue::Null, Into::into)
+    }
+}
+
+/// Full-text match inside a filter (`search.ismatch` / `search.ismatchscoring`)
+#[derive(Debug, Clone, PartialEq)]
+pub struct IsMatch {
+    /// Query text
+    pub search: String,
+    /// Fields to search in
+    pub search_fields: Option<Vec<String>>,
+    /// Query syntax
+    pub query_type: Option<QueryType>,
+    /// Search mode
+    pub search_mode: Option<SearchMode>,
+    /// Whether matching documents contribute to the relevance score
+    pub scoring: bool,
+}
+
+impl IsMatch {
+    /// Restrict the match to the given fields
+    pub fn search_fields<I, S>(mut self, fields: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.search_fields = Some(fields.into_iter().map(Into::into).collect());
+        self
+    }
+
+    /// Set the query syntax
+    pub fn query_type(mut self, query_type: QueryType) -> Self {
+        self.query_type = Some(query_type);
+        self
+    }
+
+    /// Set the search mode
+    pub fn search_mode(mut self, search_mode: SearchMode) -> Self {
+        self.search_mode = Some(search_mode);
+        self
+    }
+}
+
+/// OData filter expression
+#[derive(Debug, Clone, PartialEq)]
+pub enum Filter {
+    /// `field op value`
+    Comparison {
+        field: String,
+        operator: ComparisonOperator,
+        value: FilterValue,
+    },
+    /// Boolean field or lambda variable used as a predicate
+    Field(String),
+    /// `true` or `false`
+    Constant(bool),
+    /// All filters must match
+    And(Vec<Filter>),
+    /// Any filter must match
+    Or(Vec<Filter>),
+    /// The filter must not match
+    Not(Box<Filter>),
+    /// `search.in(field, 'values', 'delimiter')`
+    SearchIn { field: String, values: Vec<String> },
+    /// `search.ismatch(...)` or `search.ismatchscoring(...)`
+    IsMatch(IsMatch),
+    /// `collection/any()` or `collection/any(var: predicate)`
+    Any {
+        collection: String,
+        lambda: Option<(String, Box<Filter>)>,
+    },
+    /// `collection/all(var: predicate)`
+    All {
+        collection: String,
+        variable: String,
+        predicate: Box<Filter>,
+    },
+    /// `geo.distance(field, point) op distance`, with the distance in kilometers
+    GeoDistance {
+        field: String,
+        point: GeoPoint,
+        operator: ComparisonOperator,
+        kilometers: f64,
+    },
+    /// `geo.intersects(field, polygon)`
+    GeoIntersects {
+        field: String,
+        polygon: Vec<GeoPoint>,
+    },
+    /// Pre-rendered expression, passed through unchanged
+    Raw(String),
+}
+
+impl Filter {
+    /// Compare a field with a value
+    pub fn compare(
+        field: impl Into<String>,
+        operator: ComparisonOperator,
+        value: impl Into<FilterValue>,
+    ) -> Self {
+        Filter::Comparison {
+            field: field.into(),
+            operator,
+            value: value.into(),
+        }
+    }
+
+    /// `field eq value`
+    pub fn eq(field: impl Into<String>, value: impl Into<FilterValue>) -> Self {
+        Self::compare(field, ComparisonOperator::Eq, value)
+    }
+
+    /// `field ne value`
+    pub fn ne(field: impl Into<String>, value: impl Into<FilterValue>) -> Self {
+        Self::compare(field, ComparisonOperator::Ne, value)
+    }
+
+    /// `field gt value`
+    pub fn gt(field: impl Into<String>, value: impl Into<FilterValue>) -> Self {
+        Self::compare(field, ComparisonOperator::Gt, value)
+    }
+
+    /// `field ge value`
+    pub fn ge(field: impl Into<String>, value: impl Into<FilterValue>) -> Self {
+        Self::compare(field, ComparisonOperator::Ge, value)
+    }
+
+    /// `field lt value`
+    pub fn lt(field: impl Into<String>, value: impl Into<FilterValue>) -> Self {
+        Self::compare(field, ComparisonOperator::Lt, value)
+    }
+
+    /// `field le value`
+    pub fn le(field: impl Into<String>, value: impl Into<FilterValue>) -> Self {
+        Self::compare(field, ComparisonOperator::Le, value)
+    }
+
+    /// `field eq null`
+    pub fn is_null(field: impl Into<String>) -> Self {
+        Self::eq(field, FilterValue::Null)
+    }
+
+    /// `field ne null`
+    pub fn is_not_null(field: impl Into<String>) -> Self {
+        Self::ne(field, FilterValue::Null)
+    }
+
+    /// Boolean field used as a predicate
+    pub fn field(field: impl Into<String>) -> Self {
+        Filter::Field(field.into())
+    }
+
+    /// Match if the field equals any of the values
+    pub fn search_in<I, S>(field: impl Into<String>, values: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        Filter::SearchIn {
+            field: field.into(),
+            values: values.into_iter().map(Into::into).collect(),
+        }
+    }
+
+    /// Full-text match that does not affect scoring (`search.ismatch`)
+    pub fn is_match(search: impl Into<String>) -> IsMatch {
+        IsMatch {
+            search: search.into(),
+            search_fields: None,
+            query_type: None,
+            search_mode: None,
+            scoring: false,
+        }
+    }
+
+    /// Full-text match that contributes to scoring (`search.ismatchscoring`)
+    pub fn is_match_scoring(search: impl Into<String>) -> IsMatch {
+        IsMatch {
+            scoring: true,
+            ..Self::is_match(search)
+        }
+    }
+
+    /// `collection/any(variable: predicate)`
+    pub fn any(
+        collection: impl Into<String>,
+        variable: impl Into<String>,
+        predicate: Filter,
+    ) -> Self {
+        Filter::Any {
+            collection: collection.into(),
+            lambda: Some((variable.into(), Box::new(predicate))),
+        }
+    }
+
+    /// `collection/any()`: the collection is not empty
+    pub fn not_empty(collection: impl Into<String>) -> Self {
+        Filter::Any {
+            collection: collection.into(),
+            lambda: None,
+        }
+    }
+
+    /// `collection/all(variable: predicate)`
+    pub fn all(
+        collection: impl Into<String>,
+        variable: impl Into<String>,
+        predicate: Filter,
+    ) -> Self {
+        Filter::All {
+            collection: collection.into(),
+            variable: variable.into(),
+            predicate: Box::new(predicate),
+        }
+    }
+
+    /// `geo.distance(field, point) op kilometers`
+    pub fn geo_distance(
+        field: impl Into<String>,
+        point: GeoPoint,
+        operator: ComparisonOperator,
+        kilometers: f64,
+    ) -> Self {
+        Filter::GeoDistance {
+            field: field.into(),
+            point,
+            operator,
+            kilometers,
+        }
+    }
+
+    /// `geo.intersects(field, polygon)`; the polygon is closed automatically
+    pub fn geo_intersects(
+        field: impl Into<String>,
+        polygon: impl IntoIterator<Item = GeoPoint>,
+    ) -> Self {
+        Filter::GeoIntersects {
+            field: field.into(),
+            polygon: polygon.into_iter().collect(),
+        }
+    }
+
+    /// Pre-rendered expression, passed through unchanged
+    pub fn raw(expression: impl Into<String>) -> Self {
+        Filter::Raw(expression.into())
+    }
+
+    /// Combine with another filter using `and`
+    pub fn and(self, other: impl Into<Filter>) -> Self {
+        match self {
+            Filter::And(mut filters) => {
+                filters.push(other.into());
+                Filter::And(filters)
+            }
+            filter => Filter::And(vec![filter, other.into()]),
+        }
+    }
+
+    /// Combine with another filter using `or`
+    pub fn or(self, other: impl Into<Filter>) -> Self {
+        match self {
+            Filter::Or(mut filters) => {
+                filters.push(other.into());
+                Filter::Or(filters)
+            }
+            filter => Filter::Or(vec![filter, other.into()]),
+        }
+    }
+
+    /// Negate the filter
+    #[allow(clippy::should_implement_trait)]
+    pub fn not(self) -> Self {
+        Filter::Not(Box::new(self))
+    }
+
+    /// Whether the expression needs parentheses when nested
+    fn is_compound(&self) -> bool {
+        match self {
+            Filter::And(filters) | Filter::Or(filters) => filters.len() > 1,
+            // Rendered as `or`-joined equality checks without a usable delimiter
+            Filter::SearchIn { values, .. } => values.len() > 1 && pick_delimiter(values).is_none(),
+            Filter::Raw(_) => true,
+            _ => false,
+        }
+    }
+
+    fn write_nested(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_compound() {
+            write!(f, "({})", self)
+        } else {
+            write!(f, "{}", self)
+        }
+    }
+
+    fn write_joined(f: &mut fmt::Formatter<'_>, filters: &[Filter], operator: &str) -> fmt::Result {
+        if filters.is_empty() {
+            // Neutral element: empty `and` matches everything, empty `or` nothing
+            return f.write_str(if operator == "and" { "true" } else { "false" });
+        }
+        if filters.len() == 1 {
+            return write!(f, "{}", filters[0]);
+        }
+        for (position, filter) in filters.iter().enumerate() {
+            if position > 0 {
+                write!(f, " {} ", operator)?;
+            }
+            filter.write_nested(f)?;
+        }
+        Ok(())
+    }
+}
+
+impl From<IsMatch> for Filter {
+    fn from(is_match: IsMatch) -> Self {
+        Filter::IsMatch(is_match)
+    }
+}
+
+impl fmt::Display for Filter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Filter::Comparison {
+                field,
+                operator,
+                value,
+            } => write!(f, "{} {} {}", field, operator.as_str(), value),
+            Filter::Field(field) => f.write_str(field),
+            Filter::Constant(value) => write!(f, "{}", value),
+            Filter::And(filters) => Filter::write_joined(f, filters, "and"),
+            Filter::Or(filters) => Filter::write_joined(f, filters, "or"),
+            Filter::Not(filter) => write!(f, "not ({})", filter),
+            Filter::SearchIn { field, values } => match pick_delimiter(values) {
+                Some(delimiter) => write!(
+                    f,
+                    "search.in({}, {}, {})",
+                    field,
+                    quote_string(&values.join(delimiter)),
+                    quote_string(delimiter)
+                ),
+                // Every candidate delimiter occurs in a value: fall back to equality checks
+                None => {
+                    let filters: Vec<Filter> = values
+                        .iter()
+                        .map(|value| Filter::eq(field.as_str(), value))
+                        .collect();
+                    Filter::write_joined(f, &filters, "or")
+                }
+            },
+            Filter::IsMatch(is_match) => {
+                let function = if is_match.scoring {
+                    "search.ismatchscoring"
+                } else {
+                    "search.ismatch"
+                };
+                write!(f, "{}({}", function, quote_string(&is_match.search))?;
+                let has_options = is_match.query_type.is_some() || is_match.search_mode.is_some();
+                match &is_match.search_fields {
+                    Some(fields) => write!(f, ", {}", quote_string(&fields.join(",")))?,
+                    None if has_options => f.write_str(", null")?,
+                    None => {}
+                }
+                if has_options {
+                    let query_type = match is_match.query_type {
+                        Some(QueryType::Full) => "full",
+                        _ => "simple",
+                    };
+                    let search_mode = match is_match.search_mode {
+                        Some(SearchMode::All) => "all",
+                        _ => "any",
+                    };
+                    write!(f, ", '{}', '{}'", query_type, search_mode)?;
+                }
+                f.write_str(")")
+            }
+            Filter::Any { collection, lambda } => match lambda {
+                Some((variable, predicate)) => {
+                    write!(f, "{}/any({}: {})", collection, variable, predicate)
+                }
+                None => write!(f, "{}/any()", collection),
+            },
+            Filter::All {
+                collection,
+                variable,
+                predicate,
+            } => write!(f, "{}/all({}: {})", collection, variable, predicate),
+            Filter::GeoDistance {
+                field,
+                point,
+                operator,
+                kilometers,
+            } => {
+                write!(f, "geo.distance({}, ", field)?;
+                write_point(f, point)?;
+                write!(f, ") {} ", operator.as_str())?;
+                write_double(f, *kilometers)
+            }
+            Filter::GeoIntersects { field, polygon } => {
+                write!(f, "geo.intersects({}, geography'POLYGON((", field)?;
+                let closed = polygon.first() != polygon.last();
+                let first = polygon.first().filter(|_| closed);
+                for (position, point) in polygon.iter().chain(first).enumerate() {
+                    if position > 0 {
+                        f.write_str(", ")?;
+                    }
+                    write!(f, "{} {}", point.longitude, point.latitude)?;
+                }
+                f.write_str("))')")
+            }
+            Filter::Raw(expression) => f.write_str(expression),
+        }
+    }
+}
+
+impl From<Filter> for String {
+    fn from(filter: Filter) -> Self {
+        filter.to_string()
+    }
+}
+
+/// Quote a string literal, doubling embedded single quotes
+pub fn quote_string(value: &str) -> String {
+    format!("'{}'", value.replace('\'', "''"))
+}
+
+/// First delimiter that does not occur in any value
+fn pick_delimiter(values: &[String]) -> Option<&'static str> {
+    [",", "|", ";", "~", "^", "\u{1f}"]
+        .into_iter()
+        .find(|delimiter| values.iter().all(|value| !value.contains(delimiter)))
+}
+
+/// Whether the text matches `YYYY-MM-DDThh:mm[:ss[.fff]](Z|±hh:mm)`
+fn is_date_time_offset(value: &str) -> bool {
+    fn number(bytes: &[u8], start: usize, len: usize) -> Option<u32> {
+        let digits = bytes.get(start..start + len)?;
+        if !digits.iter().all(u8::is_ascii_digit) {
+            return None;
+        }
+        Some(digits.iter().fold(0, |n, d| n * 10 + u32::from(d - b'0')))
+    }
+    fn check(bytes: &[u8]) -> Option<()> {
+        let year = number(bytes, 0, 4)?;
+        let month = number(bytes, 5, 2)?;
+        let day = number(bytes, 8, 2)?;
+        let hour = number(bytes, 11, 2)?;
+        let minute = number(bytes, 14, 2)?;
+        if bytes[4] != b'-' || bytes[7] != b'-' || bytes[10] != b'T' || bytes[13] != b':' {
+            return None;
+        }
+        let leap = year % 4 == 0 && (year % 100 != 0 || year % 400 == 0);
+        let days_in_month = match month {
+            1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+            4 | 6 | 9 | 11 => 30,
+            2 if leap => 29,
+            2 => 28,
+            _ => return None,
+        };
+        if day == 0 || day > days_in_month || hour > 23 || minute > 59 {
+            return None;
+        }
+
+        let mut rest = &bytes[16..];
+        if rest.first() == Some(&b':') {
+            if number(rest, 1, 2)? > 59 {
+                return None;
+            }
+            rest = &rest[3..];
+            if rest.first() == Some(&b'.') {
+                let digits = rest[1..].iter().take_while(|b| b.is_ascii_digit()).count();
+                if digits == 0 {
+                    return None;
+                }
+                rest = &rest[1 + digits..];
+            }
+        }
+        match rest {
+            b"Z" => Some(()),
+            [b'+' | b'-', offset @ ..] if offset.len() == 5 && offset[2] == b':' => {
+                (number(offset, 0, 2)? <= 23 && number(offset, 3, 2)? <= 59).then_some(())
+            }
+            _ => None,
+        }
+    }
+    check(value.as_bytes()).is_some()
+}
+
+/// Proleptic Gregorian date of a number of days since 1970-01-01
+fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let days = days + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days - era * 146_097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1_460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index = (5 * day_of_year + 2) / 153;
+    let day = (day_of_year - (153 * month_index + 2) / 5 + 1) as u32;
+    let month = if month_index < 10 {
+        month_index + 3
+    } else {
+        month_index - 9
+    } as u32;
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+    (year, month, day)
+}
+
+fn write_double(f: &mut fmt::Formatter<'_>, value: f64) -> fmt::Result {
+    if value.is_nan() {
+        f.write_str("NaN")
+    } else if value.is_infinite() {
+        f.write_str(if value > 0.0 { "INF" } else { "-INF" })
+    } else {
+        write!(f, "{:?}", value)
+    }
+}
+
+fn write_point(f: &mut fmt::Formatter<'_>, point: &GeoPoint) -> fmt::Result {
+    write!(
+        f,
+        "geography'POINT({} {})'",
+        point.longitude, point.latitude
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const HOSTILE: &str = "x' or true or 'a' eq 'a";
+
+    #[test]
+    fn string_values_cannot_break_out_of_the_literal() {
+        let filter = Filter::eq("name", HOSTILE);
+        assert_eq!(filter.to_string(), "name eq 'x'' or true or ''a'' eq ''a'");
+        assert_eq!(FilterValue::from("''").to_string(), "''''''");
+        assert_eq!(FilterValue::from("").to_string(), "''");
+    }
+
+    #[test]
+    fn every_value_renders_as_a_single_literal() {
+        let values = [
+            (FilterValue::Null, "null"),
+            (FilterValue::from(None::<i64>), "null"),
+            (FilterValue::from(true), "true"),
+            (FilterValue::from(i64::MIN), "-9223372036854775808"),
+            (FilterValue::from(-0.5), "-0.5"),
+            (FilterValue::from(1e300), "1e300"),
+            (FilterValue::from(f64::NAN), "NaN"),
+            (FilterValue::from(f64::INFINITY), "INF"),
+            (FilterValue::from(f64::NEG_INFINITY), "-INF"),
+            (
+                FilterValue::from(HOSTILE.to_string()),
+                "'x'' or true or ''a'' eq ''a'",
+            ),
+            (
+                FilterValue::from(GeoPoint::new(-122.1, 47.6)),
+                "geography'POINT(-122.1 47.6)'",
+            ),
+            (
+                FilterValue::from(DateTimeOffset::from_str("2024-01-01T00:00:00Z").unwrap()),
+                "2024-01-01T00:00:00Z",
+            ),
+        ];
+        for (value, expected) in values {
+            assert_eq!(value.to_string(), expected);
+        }
+    }
+
+    #[test]
+    fn date_time_offsets_are_validated() {
+        for valid in [
+            "2024-01-01T10:00Z",
+            "2024-02-29T23:59:59Z",
+            "2024-01-01T10:00:00.1234567+05:30",
+            "1999-12-31T00:00:00-08:00",
+        ] {
+            assert_eq!(DateTimeOffset::from_str(valid).unwrap().as_str(), valid);
+        }
+        for hostile in [
+            "",
+            "2024-01-01",
+            "2024-01-01T00:00:00",
+            "2024-01-01T00:00:00Z or true",
+            "2024-01-01T00:00:00Z) or (true",
+            "2024-01-01T00:00:00+0530",
+            "2023-02-29T00:00:00Z",
+            "2024-13-01T00:00:00Z",
+            "2024-01-01T24:00:00Z",
+            "2024-01-01T00:00:00.Z",
+            "2024-01-01 00:00:00Z",
+            "２０２４-01-01T00:00:00Z",
+        ] {
+            assert_eq!(
+                DateTimeOffset::try_from(hostile),
+                Err(InvalidDateTimeOffset(hostile.to_string())),
+                "{}",
+                hostile
+            );
+        }
+    }
+
+    #[test]
+    fn date_time_offset_from_system_time() {
+        let cases = [
+            (0, 0, "1970-01-01T00:00:00Z"),
+            (784_111_777, 0, "1994-11-06T08:49:37Z"),
+            (1_709_164_800, 250_000_000, "2024-02-29T00:00:00.25Z"),
+            (4_102_444_799, 1, "2099-12-31T23:59:59.000000001Z"),
+        ];
+        for (seconds, nanos, expected) in cases {
+            let time = UNIX_EPOCH + Duration::new(seconds, nanos);
+            let value = DateTimeOffset::from(time);
+            assert_eq!(value.as_str(), expected);
+            assert_eq!(value.as_str().parse(), Ok(value.clone()));
+        }
+
+        let before = UNIX_EPOCH - Duration::from_millis(1_500);
+        assert_eq!(
+            DateTimeOffset::from(before).as_str(),
+            "1969-12-31T23:59:58.5Z"
+        );
+        assert_eq!(
+            Filter::ge("lastRenovated", UNIX_EPOCH).to_string(),
+            "lastRenovated ge 1970-01-01T00:00:00Z"
+        );
+    }
+
+    #[test]
+    fn search_in_picks_a_delimiter_absent_from_values() {
+        let filter = Filter::search_in("tags", ["a,b", "c'd"]);
+        assert_eq!(filter.to_string(), "search.in(tags, 'a,b|c''d', '|')");
+
+        let every_delimiter = [",", "|", ";", "~", "^", "\u{1f}"].concat();
+        let filter = Filter::search_in("tags", [every_delimiter.as_str(), "x"]);
+        assert_eq!(
+            filter.to_string(),
+            format!("tags eq '{}' or tags eq 'x'", every_delimiter)
+        );
+
+        // The fallback must not widen an enclosing `and`
+        let filter = Filter::eq("tenant", 1).and(filter);
+        assert_eq!(
+            filter.to_string(),
+            format!(
+                "tenant eq 1 and (tags eq '{}' or tags eq 'x')",
+                every_delimiter
+            )
+        );
+        assert_eq!(
+            Filter::eq("tenant", 1)
+                .and(Filter::search_in("tags", ["a", "b"]))
+                .to_string(),
+            "tenant eq 1 and search.in(tags, 'a,b', ',')"
+        );
+    }
+
+    #[test]
+    fn is_match_escapes_query_and_fields() {
+        let filter = Filter::from(
+            Filter::is_match_scoring(HOSTILE)
+                .search_fields(["it's"])
+                .search_mode(SearchMode::All),
+        );
+        assert_eq!(
+            filter.to_string(),
+            "search.ismatchscoring('x'' or true or ''a'' eq ''a', 'it''s', 'simple', 'all')"
+        );
+        assert_eq!(
+            Filter::from(Filter::is_match("a").query_type(QueryType::Full)).to_string(),
+            "search.ismatch('a', null, 'full', 'any')"
+        );
+    }
+
+    #[test]
+    fn nesting_adds_parentheses() {
+        let filter = Filter::eq("a", 1)
+            .and(Filter::eq("b", 2).or(Filter::eq("c", 3)))
+            .and(Filter::raw("d eq 4").not());
+        assert_eq!(
+            filter.to_string(),
+            "a eq 1 and (b eq 2 or c eq 3) and not (d eq 4)"
+        );
+        assert_eq!(Filter::And(Vec::new()).to_string(), "true");
+        assert_eq!(Filter::Or(Vec::new()).to_string(), "false");
+        assert_eq!(
+            Filter::any("rooms", "r", Filter::le("r/price", 100)).to_string(),
+            "rooms/any(r: r/price le 100)"
+        );
+    }
+
+    #[test]
+    fn geo_polygons_are_closed() {
+        let filter = Filter::geo_intersects(
+            "location",
+            [
+                GeoPoint::new(0.0, 0.0),
+                GeoPoint::new(1.0, 0.0),
+                GeoPoint::new(1.0, 1.0),
+            ],
+        );
+        assert_eq!(
+            filter.to_string(),
+            "geo.intersects(location, geography'POLYGON((0 0, 1 0, 1 1, 0 0))')"
+        );
+        let filter = Filter::geo_distance(
+            "location",
+            GeoPoint::new(2.5, 3.0),
+            ComparisonOperator::Lt,
+            10.0,
+        );
+        assert_eq!(
+            filter.to_string(),
+            "geo.distance(location, geography'POINT(2.5 3)') lt 10.0"
+        );
+    }
+}
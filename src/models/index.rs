@@ -0,0 +1,939 @@
+use std::collections::HashMap;
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+use super::analysis::{
+    CharFilter, LexicalAnalyzer, LexicalNormalizer, LexicalTokenizer, TokenFilter,
+};
+use super::semantic_search::SemanticSearch;
+use super::vector_search::VectorSearch;
+use crate::error::ValidationError;
+
+/// Definition of a search index
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct SearchIndex {
+    /// Index name
+    pub name: String,
+    /// Index description
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// Fields of the index
+    pub fields: Vec<SearchField>,
+    /// Scoring profile used when a query doesn't name one
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_scoring_profile: Option<String>,
+    /// Scoring profiles
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scoring_profiles: Option<Vec<ScoringProfile>>,
+    /// Suggesters used by suggest and autocomplete
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub suggesters: Option<Vec<Suggester>>,
+    /// Cross-origin requests allowed from browsers
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cors_options: Option<CorsOptions>,
+    /// Custom analyzers
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub analyzers: Option<Vec<LexicalAnalyzer>>,
+    /// Custom tokenizers
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tokenizers: Option<Vec<LexicalTokenizer>>,
+    /// Custom token filters
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub token_filters: Option<Vec<TokenFilter>>,
+    /// Custom char filters
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub char_filters: Option<Vec<CharFilter>>,
+    /// Custom normalizers
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub normalizers: Option<Vec<LexicalNormalizer>>,
+    /// Similarity algorithm used for scoring
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub similarity: Option<Value>,
+    /// Semantic ranking configuration
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub semantic: Option<SemanticSearch>,
+    /// Vector search configuration
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub vector_search: Option<VectorSearch>,
+    /// Customer-managed key used to encrypt the index
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub encryption_key: Option<EncryptionKey>,
+    /// ETag of the index definition
+    #[serde(rename = "@odata.etag", skip_serializing_if = "Option::is_none")]
+    pub e_tag: Option<String>,
+    /// Properties not modeled by this version of the library, sent back
+    /// unchanged so that updating a fetched index doesn't drop them
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+impl SearchIndex {
+    /// Create an index definition
+    pub fn new(name: impl Into<String>, fields: Vec<SearchField>) -> Self {
+        Self {
+            name: name.into(),
+            fields,
+            ..Default::default()
+        }
+    }
+
+    /// Set the description
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// Add a field
+    pub fn field(mut self, field: SearchField) -> Self {
+        self.fields.push(field);
+        self
+    }
+
+    /// Add a scoring profile
+    pub fn scoring_profile(mut self, profile: ScoringProfile) -> Self {
+        self.scoring_profiles
+            .get_or_insert_with(Vec::new)
+            .push(profile);
+        self
+    }
+
+    /// Set the scoring profile used when a query doesn't name one
+    pub fn default_scoring_profile(mut self, name: impl Into<String>) -> Self {
+        self.default_scoring_profile = Some(name.into());
+        self
+    }
+
+    /// Add a suggester
+    pub fn suggester(mut self, suggester: Suggester) -> Self {
+        self.suggesters.get_or_insert_with(Vec::new).push(suggester);
+        self
+    }
+
+    /// Set the CORS options
+    pub fn cors_options(mut self, cors_options: CorsOptions) -> Self {
+        self.cors_options = Some(cors_options);
+        self
+    }
+
+    /// Add a custom analyzer
+    pub fn analyzer(mut self, analyzer: impl Into<LexicalAnalyzer>) -> Self {
+        self.analyzers
+            .get_or_insert_with(Vec::new)
+            .push(analyzer.into());
+        self
+    }
+
+    /// Add a custom tokenizer
+    pub fn tokenizer(mut self, tokenizer: impl Into<LexicalTokenizer>) -> Self {
+        self.tokenizers
+            .get_or_insert_with(Vec::new)
+            .push(tokenizer.into());
+        self
+    }
+
+    /// Add a custom token filter
+    pub fn token_filter(mut self, token_filter: impl Into<TokenFilter>) -> Self {
+        self.token_filters
+            .get_or_insert_with(Vec::new)
+            .push(token_filter.into());
+        self
+    }
+
+    /// Add a custom char filter
+    pub fn char_filter(mut self, char_filter: impl Into<CharFilter>) -> Self {
+        self.char_filters
+            .get_or_insert_with(Vec::new)
+            .push(char_filter.into());
+        self
+    }
+
+    /// Add a custom normalizer
+    pub fn normalizer(mut self, normalizer: impl Into<LexicalNormalizer>) -> Self {
+        self.normalizers
+            .get_or_insert_with(Vec::new)
+            .push(normalizer.into());
+        self
+    }
+
+    /// Set the semantic ranking configuration
+    pub fn semantic_search(mut self, semantic: SemanticSearch) -> Self {
+        self.semantic = Some(semantic);
+        self
+    }
+
+    /// Set the vector search configuration
+    pub fn vector_search(mut self, vector_search: VectorSearch) -> Self {
+        self.vector_search = Some(vector_search);
+        self
+    }
+
+    /// Set the customer-managed encryption key
+    pub fn encryption_key(mut self, encryption_key: EncryptionKey) -> Self {
+        self.encryption_key = Some(encryption_key);
+        self
+    }
+
+    /// Key field of the index
+    pub fn key_field(&self) -> Option<&SearchField> {
+        self.fields.iter().find(|field| field.key == Some(true))
+    }
+
+    /// Top-level field with the given name
+    pub fn find_field(&self, name: &str) -> Option<&SearchField> {
+        self.fields.iter().find(|field| field.name == name)
+    }
+
+    /// Check that a semantic configuration is defined in the index
+    pub fn validate_semantic_configuration(&self, name: &str) -> Result<(), ValidationError> {
+        self.semantic
+            .as_ref()
+            .and_then(|semantic| semantic.find_configuration(name))
+            .map(|_| ())
+            .ok_or_else(|| ValidationError::UnknownSemanticConfiguration {
+                name: name.to_string(),
+            })
+    }
+
+    /// Check that vector fields and profiles only reference defined configurations
+    pub fn validate_vector_search(&self) -> Result<(), ValidationError> {
+        fn check(
+            fields: &[SearchField],
+            vector_search: Option<&VectorSearch>,
+        ) -> Result<(), ValidationError> {
+            for field in fields {
+                if let Some(profile) = &field.vector_search_profile {
+                    if vector_search
+                        .and_then(|vector_search| vector_search.find_profile(profile))
+                        .is_none()
+                    {
+                        return Err(ValidationError::UnknownVectorSearchProfile {
+                            field: field.name.clone(),
+                            profile: profile.clone(),
+                        });
+                    }
+                }
+                check(field.fields.as_deref().unwrap_or_default(), vector_search)?;
+            }
+            Ok(())
+        }
+
+        check(&self.fields, self.vector_search.as_ref())?;
+        match &self.vector_search {
+            Some(vector_search) => vector_search.validate(),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Entity Data Model type of a field
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+#[serde(into = "String", from = "String")]
+pub enum SearchFieldDataType {
+    /// Edm.String
+    String,
+    /// Edm.Int32
+    Int32,
+    /// Edm.Int64
+    Int64,
+    /// Edm.Int16 (vector fields only)
+    Int16,
+    /// Edm.SByte (vector fields only)
+    SByte,
+    /// Edm.Byte (vector fields only)
+    Byte,
+    /// Edm.Double
+    Double,
+    /// Edm.Single (vector fields only)
+    Single,
+    /// Edm.Half (vector fields only)
+    Half,
+    /// Edm.Boolean
+    Boolean,
+    /// Edm.DateTimeOffset
+    DateTimeOffset,
+    /// Edm.GeographyPoint
+    GeographyPoint,
+    /// Edm.ComplexType
+    ComplexType,
+    /// Collection(...)
+    Collection(Box<SearchFieldDataType>),
+    /// Type not known to this version of the library
+    Other(String),
+}
+
+impl SearchFieldDataType {
+    /// Collection of the given type
+    pub fn collection(item: SearchFieldDataType) -> Self {
+        SearchFieldDataType::Collection(Box::new(item))
+    }
+
+    /// Whether this is a collection type
+    pub fn is_collection(&self) -> bool {
+        matches!(self, SearchFieldDataType::Collection(_))
+    }
+
+    /// Whether this is a complex type or a collection of complex types
+    pub fn is_complex(&self) -> bool {
+        match self {
+            SearchFieldDataType::ComplexType => true,
+            SearchFieldDataType::Collection(item) => item.is_complex(),
+            _ => false,
+        }
+    }
+}
+
+impl fmt::Display for SearchFieldDataType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SearchFieldDataType::String => f.write_str("Edm.String"),
+            SearchFieldDataType::Int32 => f.write_str("Edm.Int32"),
+            SearchFieldDataType::Int64 => f.write_str("Edm.Int64"),
+            SearchFieldDataType::Int16 => f.write_str("Edm.Int16"),
+            SearchFieldDataType::SByte => f.write_str("Edm.SByte"),
+            SearchFieldDataType::Byte => f.write_str("Edm.Byte"),
+            SearchFieldDataType::Double => f.write_str("Edm.Double"),
+            SearchFieldDataType::Single => f.write_str("Edm.Single"),
+            SearchFieldDataType::Half => f.write_str("Edm.Half"),
+            SearchFieldDataType::Boolean => f.write_str("Edm.Boolean"),
+            SearchFieldDataType::DateTimeOffset => f.write_str("Edm.DateTimeOffset"),
+            SearchFieldDataType::GeographyPoint => f.write_str("Edm.GeographyPoint"),
+            SearchFieldDataType::ComplexType => f.write_str("Edm.ComplexType"),
+            SearchFieldDataType::Collection(item) => write!(f, "Collection({})", item),
+            SearchFieldDataType::Other(other) => f.write_str(other),
+        }
+    }
+}
+
+impl From<String> for SearchFieldDataType {
+    fn from(s: String) -> Self {
+        if let Some(item) = s
+            .strip_prefix("Collection(")
+            .and_then(|rest| rest.strip_suffix(')'))
+        {
+            return SearchFieldDataType::collection(item.to_string().into());
+        }
+        match s.as_str() {
+            "Edm.String" => SearchFieldDataType::String,
+            "Edm.Int32" => SearchFieldDataType::Int32,
+            "Edm.Int64" => SearchFieldDataType::Int64,
+            "Edm.Int16" => SearchFieldDataType::Int16,
+            "Edm.SByte" => SearchFieldDataType::SByte,
+            "Edm.Byte" => SearchFieldDataType::Byte,
+            "Edm.Double" => SearchFieldDataType::Double,
+            "Edm.Single" => SearchFieldDataType::Single,
+            "Edm.Half" => SearchFieldDataType::Half,
+            "Edm.Boolean" => SearchFieldDataType::Boolean,
+            "Edm.DateTimeOffset" => SearchFieldDataType::DateTimeOffset,
+            "Edm.GeographyPoint" => SearchFieldDataType::GeographyPoint,
+            "Edm.ComplexType" => SearchFieldDataType::ComplexType,
+            _ => SearchFieldDataType::Other(s),
+        }
+    }
+}
+
+impl From<SearchFieldDataType> for String {
+    fn from(data_type: SearchFieldDataType) -> Self {
+        data_type.to_string()
+    }
+}
+
+/// Field of an index, or a sub-field of a complex field
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct SearchField {
+    /// Field name
+    pub name: String,
+    /// Field type
+    #[serde(rename = "type")]
+    pub field_type: SearchFieldDataType,
+    /// Whether the field is the document key
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub key: Option<bool>,
+    /// Whether the field is returned in results
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub retrievable: Option<bool>,
+    /// Whether the field is stored and can be returned (vector fields)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stored: Option<bool>,
+    /// Whether the field is full-text searchable
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub searchable: Option<bool>,
+    /// Whether the field can be used in filters
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub filterable: Option<bool>,
+    /// Whether the field can be used in orderby
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sortable: Option<bool>,
+    /// Whether the field can be faceted
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub facetable: Option<bool>,
+    /// Analyzer used for indexing and searching
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub analyzer: Option<String>,
+    /// Analyzer used for searching only
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub search_analyzer: Option<String>,
+    /// Analyzer used for indexing only
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub index_analyzer: Option<String>,
+    /// Normalizer used for filtering, sorting and faceting
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub normalizer: Option<String>,
+    /// Synonym maps applied to the field
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub synonym_maps: Option<Vec<String>>,
+    /// Number of dimensions of a vector field
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dimensions: Option<u32>,
+    /// Vector search profile of a vector field
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub vector_search_profile: Option<String>,
+    /// Encoding of a vector field (e.g. `packedBit`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub vector_encoding: Option<String>,
+    /// Sub-fields of a complex field
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fields: Option<Vec<SearchField>>,
+    /// Attributes not modeled by this version of the library
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+impl SearchField {
+    /// Field of the given type with default attributes
+    pub fn new(name: impl Into<String>, field_type: SearchFieldDataType) -> Self {
+        Self {
+            name: name.into(),
+            field_type,
+            key: None,
+            retrievable: None,
+            stored: None,
+            searchable: None,
+            filterable: None,
+            sortable: None,
+            facetable: None,
+            analyzer: None,
+            search_analyzer: None,
+            index_analyzer: None,
+            normalizer: None,
+            synonym_maps: None,
+            dimensions: None,
+            vector_search_profile: None,
+            vector_encoding: None,
+            fields: None,
+            extra: Map::new(),
+        }
+    }
+
+    /// Searchable `Edm.String` field
+    pub fn searchable(name: impl Into<String>) -> Self {
+        Self::new(name, SearchFieldDataType::String).with_searchable(true)
+    }
+
+    /// Complex field, or collection of complex fields, with the given sub-fields
+    pub fn complex(name: impl Into<String>, fields: Vec<SearchField>, collection: bool) -> Self {
+        let field_type = if collection {
+            SearchFieldDataType::collection(SearchFieldDataType::ComplexType)
+        } else {
+            SearchFieldDataType::ComplexType
+        };
+        Self {
+            fields: Some(fields),
+            ..Self::new(name, field_type)
+        }
+    }
+
+    /// `Collection(Edm.Single)` vector field searched through a vector profile
+    pub fn vector(
+        name: impl Into<String>,
+        dimensions: u32,
+        vector_search_profile: impl Into<String>,
+    ) -> Self {
+        Self {
+            dimensions: Some(dimensions),
+            vector_search_profile: Some(vector_search_profile.into()),
+            ..Self::new(
+                name,
+                SearchFieldDataType::collection(SearchFieldDataType::Single),
+            )
+            .with_searchable(true)
+        }
+    }
+
+    /// Mark the field as the document key
+    pub fn with_key(mut self, key: bool) -> Self {
+        self.key = Some(key);
+        self
+    }
+
+    /// Set whether the field is returned in results
+    pub fn with_retrievable(mut self, retrievable: bool) -> Self {
+        self.retrievable = Some(retrievable);
+        self
+    }
+
+    /// Set whether the field is stored
+    pub fn with_stored(mut self, stored: bool) -> Self {
+        self.stored = Some(stored);
+        self
+    }
+
+    /// Set whether the field is full-text searchable
+    pub fn with_searchable(mut self, searchable: bool) -> Self {
+        self.searchable = Some(searchable);
+        self
+    }
+
+    /// Set whether the field can be used in filters
+    pub fn with_filterable(mut self, filterable: bool) -> Self {
+        self.filterable = Some(filterable);
+        self
+    }
+
+    /// Set whether the field can be used in orderby
+    pub fn with_sortable(mut self, sortable: bool) -> Self {
+        self.sortable = Some(sortable);
+        self
+    }
+
+    /// Set whether the field can be faceted
+    pub fn with_facetable(mut self, facetable: bool) -> Self {
+        self.facetable = Some(facetable);
+        self
+    }
+
+    /// Set the analyzer used for indexing and searching
+    pub fn with_analyzer(mut self, analyzer: impl Into<String>) -> Self {
+        self.analyzer = Some(analyzer.into());
+        self
+    }
+
+    /// Set separate analyzers for searching and indexing
+    pub fn with_search_and_index_analyzers(
+        mut self,
+        search_analyzer: impl Into<String>,
+        index_analyzer: impl Into<String>,
+    ) -> Self {
+        self.search_analyzer = Some(search_analyzer.into());
+        self.index_analyzer = Some(index_analyzer.into());
+        self
+    }
+
+    /// Set the normalizer
+    pub fn with_normalizer(mut self, normalizer: impl Into<String>) -> Self {
+        self.normalizer = Some(normalizer.into());
+        self
+    }
+
+    /// Set the synonym maps
+    pub fn with_synonym_maps<I, S>(mut self, synonym_maps: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.synonym_maps = Some(synonym_maps.into_iter().map(Into::into).collect());
+        self
+    }
+
+    /// Set the vector encoding
+    pub fn with_vector_encoding(mut self, vector_encoding: impl Into<String>) -> Self {
+        self.vector_encoding = Some(vector_encoding.into());
+        self
+    }
+}
+
+/// Suggester used by suggest and autocomplete
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct Suggester {
+    /// Suggester name
+    pub name: String,
+    /// Matching strategy, always `analyzingInfixMatching`
+    pub search_mode: String,
+    /// Fields the suggestions come from
+    pub source_fields: Vec<String>,
+}
+
+impl Suggester {
+    /// Create a suggester over the given fields
+    pub fn new<I, S>(name: impl Into<String>, source_fields: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        Self {
+            name: name.into(),
+            search_mode: "analyzingInfixMatching".to_string(),
+            source_fields: source_fields.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+/// Cross-origin resource sharing options
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct CorsOptions {
+    /// Allowed origins, or `*` for all
+    pub allowed_origins: Vec<String>,
+    /// How long browsers may cache preflight responses
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_age_in_seconds: Option<u64>,
+}
+
+/// Relevance tuning applied to a query by name
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ScoringProfile {
+    /// Profile name
+    pub name: String,
+    /// Weights of matches in each searchable field
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub text: Option<TextWeights>,
+    /// Functions boosting the score
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub functions: Option<Vec<ScoringFunction>>,
+    /// How function results are combined
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub function_aggregation: Option<ScoringFunctionAggregation>,
+}
+
+impl ScoringProfile {
+    /// Create an empty profile
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            text: None,
+            functions: None,
+            function_aggregation: None,
+        }
+    }
+
+    /// Weight matches in a field
+    pub fn text_weight(mut self, field: impl Into<String>, weight: f64) -> Self {
+        self.text
+            .get_or_insert_with(Default::default)
+            .weights
+            .insert(field.into(), weight);
+        self
+    }
+
+    /// Add a scoring function
+    pub fn function(mut self, function: ScoringFunction) -> Self {
+        self.functions.get_or_insert_with(Vec::new).push(function);
+        self
+    }
+
+    /// Set how function results are combined
+    pub fn function_aggregation(mut self, aggregation: ScoringFunctionAggregation) -> Self {
+        self.function_aggregation = Some(aggregation);
+        self
+    }
+}
+
+/// Weights of matches in searchable fields
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
+pub struct TextWeights {
+    /// Weight keyed by field name
+    pub weights: HashMap<String, f64>,
+}
+
+/// Function boosting the score of a document
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ScoringFunction {
+    /// Field the function is computed on
+    pub field_name: String,
+    /// Multiplier applied to the raw score
+    pub boost: f64,
+    /// How the boost changes across the range
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub interpolation: Option<ScoringFunctionInterpolation>,
+    /// Kind of function and its parameters
+    #[serde(flatten)]
+    pub kind: ScoringFunctionKind,
+}
+
+impl ScoringFunction {
+    /// Create a function on a field
+    pub fn new(field_name: impl Into<String>, boost: f64, kind: ScoringFunctionKind) -> Self {
+        Self {
+            field_name: field_name.into(),
+            boost,
+            interpolation: None,
+            kind,
+        }
+    }
+
+    /// Set the interpolation
+    pub fn interpolation(mut self, interpolation: ScoringFunctionInterpolation) -> Self {
+        self.interpolation = Some(interpolation);
+        self
+    }
+}
+
+/// Kind of scoring function, serialized with its `type`
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum ScoringFunctionKind {
+    /// Boost by the magnitude of a numeric field
+    Magnitude {
+        magnitude: MagnitudeScoringParameters,
+    },
+    /// Boost by how recent a date field is
+    Freshness {
+        freshness: FreshnessScoringParameters,
+    },
+    /// Boost by distance from a reference point
+    Distance { distance: DistanceScoringParameters },
+    /// Boost by tags shared with the query
+    Tag { tag: TagScoringParameters },
+}
+
+/// Parameters of a magnitude function
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct MagnitudeScoringParameters {
+    /// Start of the boosted range
+    pub boosting_range_start: f64,
+    /// End of the boosted range
+    pub boosting_range_end: f64,
+    /// Apply the full boost beyond the end of the range
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub constant_boost_beyond_range: Option<bool>,
+}
+
+/// Parameters of a freshness function
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct FreshnessScoringParameters {
+    /// ISO 8601 duration after which boosting stops (e.g. `P365D`)
+    pub boosting_duration: String,
+}
+
+/// Parameters of a distance function
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct DistanceScoringParameters {
+    /// Scoring parameter carrying the reference point
+    pub reference_point_parameter: String,
+    /// Distance in kilometers after which boosting stops
+    pub boosting_distance: f64,
+}
+
+/// Parameters of a tag function
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct TagScoringParameters {
+    /// Scoring parameter carrying the tags
+    pub tags_parameter: String,
+}
+
+/// How the boost of a scoring function changes across its range
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum ScoringFunctionInterpolation {
+    /// linear
+    Linear,
+    /// constant
+    Constant,
+    /// quadratic
+    Quadratic,
+    /// logarithmic
+    Logarithmic,
+}
+
+/// How the results of scoring functions are combined
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum ScoringFunctionAggregation {
+    /// sum
+    Sum,
+    /// average
+    Average,
+    /// minimum
+    Minimum,
+    /// maximum
+    Maximum,
+    /// firstMatching
+    FirstMatching,
+}
+
+/// Customer-managed key in Azure Key Vault
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct EncryptionKey {
+    /// Name of the key
+    pub key_vault_key_name: String,
+    /// Version of the key
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub key_vault_key_version: Option<String>,
+    /// URI of the key vault
+    pub key_vault_uri: String,
+    /// Application used to access the key vault
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub access_credentials: Option<AccessCredentials>,
+    /// Managed identity used to access the key vault
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub identity: Option<Value>,
+}
+
+/// Entra ID application credentials used to access a key vault
+#[derive(Serialize, Deserialize, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct AccessCredentials {
+    /// Application (client) id
+    pub application_id: String,
+    /// Application secret
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub application_secret: Option<String>,
+}
+
+impl fmt::Debug for AccessCredentials {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("AccessCredentials")
+            .field("application_id", &self.application_id)
+            .field(
+                "application_secret",
+                &self.application_secret.as_ref().map(|_| "<redacted>"),
+            )
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::models::semantic_search::{SemanticConfiguration, SemanticPrioritizedFields};
+    use crate::models::vector_search::{HnswAlgorithm, HnswParameters, VectorSearchProfile};
+
+    #[test]
+    fn unknown_properties_survive_a_round_trip() {
+        let definition = json!({
+            "name": "hotels",
+            "fields": [
+                { "name": "id", "type": "Edm.String", "key": true },
+                {
+                    "name": "address",
+                    "type": "Edm.ComplexType",
+                    "fields": [
+                        { "name": "city", "type": "Edm.String", "futureAttribute": { "on": true } }
+                    ]
+                },
+                { "name": "embedding", "type": "Collection(Edm.Half)", "dimensions": 3 }
+            ],
+            "permissionFilterOption": "enabled",
+            "@odata.etag": "\"0x1\""
+        });
+
+        let index: SearchIndex = serde_json::from_value(definition.clone()).unwrap();
+        assert_eq!(index.extra["permissionFilterOption"], "enabled");
+        assert_eq!(index.e_tag.as_deref(), Some("\"0x1\""));
+        assert!(!index.extra.contains_key("@odata.etag"));
+        let city = &index.fields[1].fields.as_ref().unwrap()[0];
+        assert_eq!(city.extra["futureAttribute"], json!({ "on": true }));
+        assert!(index.fields[0].extra.is_empty());
+        assert_eq!(
+            index.fields[2].field_type,
+            SearchFieldDataType::collection(SearchFieldDataType::Half)
+        );
+
+        assert_eq!(serde_json::to_value(&index).unwrap(), definition);
+    }
+
+    #[test]
+    fn data_types_round_trip() {
+        for name in [
+            "Edm.String",
+            "Collection(Edm.ComplexType)",
+            "Collection(Collection(Edm.Int32))",
+            "Edm.Future",
+        ] {
+            let data_type = SearchFieldDataType::from(name.to_string());
+            assert_eq!(String::from(data_type), name);
+        }
+        assert!(SearchFieldDataType::collection(SearchFieldDataType::ComplexType).is_complex());
+        assert!(!SearchFieldDataType::Other("Edm.Future".to_string()).is_collection());
+    }
+
+    #[test]
+    fn vector_fields_must_reference_defined_profiles() {
+        let hnsw = HnswAlgorithm::new("hnsw", HnswParameters::default());
+        let profile = VectorSearchProfile::new("default", &hnsw);
+        let index = SearchIndex::new(
+            "hotels",
+            vec![
+                SearchField::new("id", SearchFieldDataType::String).with_key(true),
+                SearchField::complex(
+                    "rooms",
+                    vec![SearchField::vector("embedding", 3, "missing")],
+                    true,
+                ),
+            ],
+        )
+        .vector_search(VectorSearch::default().algorithm(hnsw).profile(profile));
+
+        assert_eq!(
+            index.validate_vector_search(),
+            Err(ValidationError::UnknownVectorSearchProfile {
+                field: "embedding".to_string(),
+                profile: "missing".to_string()
+            })
+        );
+
+        let without_vector_search = SearchIndex::new(
+            "hotels",
+            vec![SearchField::vector("embedding", 3, "default")],
+        );
+        assert!(without_vector_search.validate_vector_search().is_err());
+        assert_eq!(
+            SearchIndex::new("hotels", Vec::new()).validate_vector_search(),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn dangling_profile_references_are_reported() {
+        let vector_search = VectorSearch::default()
+            .algorithm(HnswAlgorithm::new("hnsw", HnswParameters::default()))
+            .profile(VectorSearchProfile::new("default", "hnsw").vectorizer("openai"));
+        let index = SearchIndex::new(
+            "hotels",
+            vec![SearchField::vector("embedding", 3, "default")],
+        )
+        .vector_search(vector_search);
+
+        assert_eq!(
+            index.validate_vector_search(),
+            Err(ValidationError::UnknownVectorSearchReference {
+                profile: "default".to_string(),
+                reference: "openai".to_string()
+            })
+        );
+    }
+
+    #[test]
+    fn semantic_configurations_are_looked_up_by_name() {
+        let index = SearchIndex::new("hotels", vec![SearchField::searchable("description")])
+            .semantic_search(
+                SemanticSearch::default().configuration(SemanticConfiguration::new(
+                    "default",
+                    SemanticPrioritizedFields::default().content_field("description"),
+                )),
+            );
+        assert_eq!(index.validate_semantic_configuration("default"), Ok(()));
+        assert_eq!(
+            index.validate_semantic_configuration("Default"),
+            Err(ValidationError::UnknownSemanticConfiguration {
+                name: "Default".to_string()
+            })
+        );
+        assert!(SearchIndex::new("hotels", Vec::new())
+            .validate_semantic_configuration("default")
+            .is_err());
+    }
+}
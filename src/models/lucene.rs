@@ -0,0 +1,855 @@
+//! Lucene full query syntax (`queryType=full`)
+//!
+//! [`LuceneQuery`] renders valid query text, [`escape`] makes user input safe
+//! to embed as a literal term, and [`validate`] checks query text client-side,
+//! reporting the byte position of the first syntax error.
+
+use std::fmt;
+
+use thiserror::Error;
+
+/// Characters with a special meaning in the Lucene query syntax
+const SPECIAL_CHARACTERS: &[char] = &[
+    '+', '-', '&', '|', '!', '(', ')', '{', '}', '[', ']', '^', '"', '~', '*', '?', ':', '\\', '/',
+];
+
+/// Escape user input so that it is matched literally
+pub fn escape(input: &str) -> String {
+    let mut escaped = String::with_capacity(input.len());
+    let mut word_start = true;
+    for (position, c) in input.char_indices() {
+        // Keep AND / OR / NOT in user input from being read as operators
+        if word_start && is_operator_word(&input[position..]) {
+            escaped.push('\\');
+        }
+        if SPECIAL_CHARACTERS.contains(&c) {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+        word_start = c.is_whitespace();
+    }
+    escaped
+}
+
+/// Whether the text starts with a whole-word boolean operator
+fn is_operator_word(text: &str) -> bool {
+    let word = text.split(char::is_whitespace).next().unwrap_or_default();
+    matches!(word, "AND" | "OR" | "NOT")
+}
+
+/// Escape a phrase; only quotes and backslashes are special inside quotes
+fn escape_phrase(input: &str) -> String {
+    input.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+/// Escape everything except the `*` and `?` wildcards
+fn escape_wildcard(input: &str) -> String {
+    let mut escaped = String::with_capacity(input.len());
+    for c in input.chars() {
+        if SPECIAL_CHARACTERS.contains(&c) && c != '*' && c != '?' {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
+}
+
+/// Escape the `/` delimiters in a regular expression
+///
+/// Escape sequences already present in the pattern, including `\/`, are
+/// kept as written; a trailing lone backslash is escaped so that it cannot
+/// swallow the closing delimiter.
+fn escape_regex(pattern: &str) -> String {
+    let mut escaped = String::with_capacity(pattern.len());
+    let mut chars = pattern.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => match chars.next() {
+                Some(next) => {
+                    escaped.push('\\');
+                    escaped.push(next);
+                }
+                None => escaped.push_str("\\\\"),
+            },
+            '/' => escaped.push_str("\\/"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Bound of a range query
+///
+/// Values are escaped; empty values and values containing whitespace are
+/// quoted, as an unquoted bound ends at the first whitespace.
+#[derive(Debug, Clone, PartialEq)]
+pub enum RangeBound {
+    /// Value included in the range (`[`/`]`)
+    Inclusive(String),
+    /// Value excluded from the range (`{`/`}`)
+    Exclusive(String),
+    /// No bound (`*`)
+    Unbounded,
+}
+
+/// Lucene query expression
+#[derive(Debug, Clone, PartialEq)]
+pub enum LuceneQuery {
+    /// Single term, escaped
+    Term(String),
+    /// Exact phrase
+    Phrase(String),
+    /// Term with `*` / `?` wildcards; other special characters are escaped
+    Wildcard(String),
+    /// Literal prefix followed by `*`
+    Prefix(String),
+    /// Regular expression, `/pattern/`
+    Regex(String),
+    /// Fuzzy term, `term~distance`
+    Fuzzy { term: String, distance: Option<u8> },
+    /// Phrase whose terms may be up to `distance` positions apart, `"a b"~distance`
+    Proximity { phrase: String, distance: u32 },
+    /// Range of values, `[a TO b]`
+    Range {
+        lower: RangeBound,
+        upper: RangeBound,
+    },
+    /// Query restricted to a field, `field:query`
+    Field {
+        field: String,
+        query: Box<LuceneQuery>,
+    },
+    /// Query with a relevance boost, `query^boost`
+    Boost { query: Box<LuceneQuery>, boost: f32 },
+    /// Clause that must match, `+query`
+    Required(Box<LuceneQuery>),
+    /// Clause that must not match, `-query`
+    Prohibited(Box<LuceneQuery>),
+    /// All queries must match
+    And(Vec<LuceneQuery>),
+    /// Any query must match
+    Or(Vec<LuceneQuery>),
+    /// The query must not match
+    Not(Box<LuceneQuery>),
+    /// Pre-rendered query text, passed through unchanged
+    Raw(String),
+}
+
+impl LuceneQuery {
+    /// Single term
+    pub fn term(term: impl Into<String>) -> Self {
+        LuceneQuery::Term(term.into())
+    }
+
+    /// Exact phrase
+    pub fn phrase(phrase: impl Into<String>) -> Self {
+        LuceneQuery::Phrase(phrase.into())
+    }
+
+    /// Wildcard term (`*` matches many characters, `?` one)
+    pub fn wildcard(pattern: impl Into<String>) -> Self {
+        LuceneQuery::Wildcard(pattern.into())
+    }
+
+    /// Prefix match, `term*`; the prefix is matched literally
+    pub fn prefix(prefix: impl Into<String>) -> Self {
+        LuceneQuery::Prefix(prefix.into())
+    }
+
+    /// Regular expression
+    pub fn regex(pattern: impl Into<String>) -> Self {
+        LuceneQuery::Regex(pattern.into())
+    }
+
+    /// Fuzzy term with the default edit distance (2)
+    pub fn fuzzy(term: impl Into<String>) -> Self {
+        LuceneQuery::Fuzzy {
+            term: term.into(),
+            distance: None,
+        }
+    }
+
+    /// Fuzzy term with an explicit edit distance (0 to 2)
+    pub fn fuzzy_with_distance(term: impl Into<String>, distance: u8) -> Self {
+        LuceneQuery::Fuzzy {
+            term: term.into(),
+            distance: Some(distance),
+        }
+    }
+
+    /// Proximity search over a phrase
+    pub fn proximity(phrase: impl Into<String>, distance: u32) -> Self {
+        LuceneQuery::Proximity {
+            phrase: phrase.into(),
+            distance,
+        }
+    }
+
+    /// Range of values
+    pub fn range(lower: RangeBound, upper: RangeBound) -> Self {
+        LuceneQuery::Range { lower, upper }
+    }
+
+    /// Pre-rendered query text
+    pub fn raw(query: impl Into<String>) -> Self {
+        LuceneQuery::Raw(query.into())
+    }
+
+    /// Restrict the query to a field
+    pub fn in_field(self, field: impl Into<String>) -> Self {
+        LuceneQuery::Field {
+            field: field.into(),
+            query: Box::new(self),
+        }
+    }
+
+    /// Boost the relevance of matches
+    pub fn boost(self, boost: f32) -> Self {
+        LuceneQuery::Boost {
+            query: Box::new(self),
+            boost,
+        }
+    }
+
+    /// Mark the clause as required (`+`)
+    pub fn required(self) -> Self {
+        LuceneQuery::Required(Box::new(self))
+    }
+
+    /// Mark the clause as prohibited (`-`)
+    pub fn prohibited(self) -> Self {
+        LuceneQuery::Prohibited(Box::new(self))
+    }
+
+    /// Combine with another query using `AND`
+    pub fn and(self, other: LuceneQuery) -> Self {
+        match self {
+            LuceneQuery::And(mut queries) => {
+                queries.push(other);
+                LuceneQuery::And(queries)
+            }
+            query => LuceneQuery::And(vec![query, other]),
+        }
+    }
+
+    /// Combine with another query using `OR`
+    pub fn or(self, other: LuceneQuery) -> Self {
+        match self {
+            LuceneQuery::Or(mut queries) => {
+                queries.push(other);
+                LuceneQuery::Or(queries)
+            }
+            query => LuceneQuery::Or(vec![query, other]),
+        }
+    }
+
+    /// Negate the query
+    #[allow(clippy::should_implement_trait)]
+    pub fn not(self) -> Self {
+        LuceneQuery::Not(Box::new(self))
+    }
+
+    /// Whether the query must be grouped when used as an operand
+    fn needs_group(&self) -> bool {
+        match self {
+            LuceneQuery::And(queries) | LuceneQuery::Or(queries) => queries.len() > 1,
+            LuceneQuery::Raw(_) => true,
+            _ => false,
+        }
+    }
+
+    fn write_operand(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.needs_group() {
+            write!(f, "({})", self)
+        } else {
+            write!(f, "{}", self)
+        }
+    }
+
+    fn write_joined(
+        f: &mut fmt::Formatter<'_>,
+        queries: &[LuceneQuery],
+        operator: &str,
+    ) -> fmt::Result {
+        for (position, query) in queries.iter().enumerate() {
+            if position > 0 {
+                write!(f, " {} ", operator)?;
+            }
+            query.write_operand(f)?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for RangeBound {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RangeBound::Inclusive(value) | RangeBound::Exclusive(value) => {
+                if value.is_empty() || value.contains(char::is_whitespace) {
+                    write!(f, "\"{}\"", escape_phrase(value))
+                } else {
+                    f.write_str(&escape(value))
+                }
+            }
+            RangeBound::Unbounded => f.write_str("*"),
+        }
+    }
+}
+
+impl fmt::Display for LuceneQuery {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LuceneQuery::Term(term) => f.write_str(&escape(term)),
+            LuceneQuery::Phrase(phrase) => write!(f, "\"{}\"", escape_phrase(phrase)),
+            LuceneQuery::Wildcard(pattern) => f.write_str(&escape_wildcard(pattern)),
+            LuceneQuery::Prefix(prefix) => write!(f, "{}*", escape(prefix)),
+            LuceneQuery::Regex(pattern) => write!(f, "/{}/", escape_regex(pattern)),
+            LuceneQuery::Fuzzy { term, distance } => match distance {
+                Some(distance) => write!(f, "{}~{}", escape(term), distance),
+                None => write!(f, "{}~", escape(term)),
+            },
+            LuceneQuery::Proximity { phrase, distance } => {
+                write!(f, "\"{}\"~{}", escape_phrase(phrase), distance)
+            }
+            LuceneQuery::Range { lower, upper } => {
+                let open = if matches!(lower, RangeBound::Exclusive(_)) {
+                    '{'
+                } else {
+                    '['
+                };
+                let close = if matches!(upper, RangeBound::Exclusive(_)) {
+                    '}'
+                } else {
+                    ']'
+                };
+                write!(f, "{}{} TO {}{}", open, lower, upper, close)
+            }
+            LuceneQuery::Field { field, query } => {
+                write!(f, "{}:", escape(field))?;
+                match query.as_ref() {
+                    LuceneQuery::Term(_)
+                    | LuceneQuery::Phrase(_)
+                    | LuceneQuery::Wildcard(_)
+                    | LuceneQuery::Prefix(_)
+                    | LuceneQuery::Regex(_)
+                    | LuceneQuery::Fuzzy { .. }
+                    | LuceneQuery::Proximity { .. }
+                    | LuceneQuery::Range { .. } => write!(f, "{}", query),
+                    query => write!(f, "({})", query),
+                }
+            }
+            LuceneQuery::Boost { query, boost } => {
+                query.write_operand(f)?;
+                write!(f, "^{}", boost)
+            }
+            LuceneQuery::Required(query) => {
+                f.write_str("+")?;
+                query.write_operand(f)
+            }
+            LuceneQuery::Prohibited(query) => {
+                f.write_str("-")?;
+                query.write_operand(f)
+            }
+            LuceneQuery::And(queries) => LuceneQuery::write_joined(f, queries, "AND"),
+            LuceneQuery::Or(queries) => LuceneQuery::write_joined(f, queries, "OR"),
+            LuceneQuery::Not(query) => {
+                f.write_str("NOT ")?;
+                query.write_operand(f)
+            }
+            LuceneQuery::Raw(query) => f.write_str(query),
+        }
+    }
+}
+
+impl From<LuceneQuery> for String {
+    fn from(query: LuceneQuery) -> Self {
+        query.to_string()
+    }
+}
+
+/// Syntax error found by [`validate`]
+#[derive(Debug, Error, Clone, PartialEq, Eq)]
+#[error("{message} at position {position}")]
+pub struct LuceneSyntaxError {
+    /// Byte offset of the error in the query text
+    pub position: usize,
+    /// Description of the error
+    pub message: String,
+}
+
+/// Check query text against the Lucene syntax accepted by the service
+pub fn validate(query: &str) -> Result<(), LuceneSyntaxError> {
+    let mut parser = Parser {
+        query,
+        chars: query.char_indices().collect(),
+        index: 0,
+    };
+    parser.parse_sequence(None)
+}
+
+struct Parser<'a> {
+    query: &'a str,
+    chars: Vec<(usize, char)>,
+    index: usize,
+}
+
+impl Parser<'_> {
+    fn peek(&self) -> Option<char> {
+        self.chars.get(self.index).map(|(_, c)| *c)
+    }
+
+    fn position(&self) -> usize {
+        self.chars
+            .get(self.index)
+            .map_or(self.query.len(), |(position, _)| *position)
+    }
+
+    fn error(&self, position: usize, message: impl Into<String>) -> LuceneSyntaxError {
+        LuceneSyntaxError {
+            position,
+            message: message.into(),
+        }
+    }
+
+    fn skip_whitespace(&mut self) {
+        while self.peek().is_some_and(char::is_whitespace) {
+            self.index += 1;
+        }
+    }
+
+    /// Parse clauses until the end of input, or until `)` when inside a group
+    /// opened at `group`
+    fn parse_sequence(&mut self, group: Option<usize>) -> Result<(), LuceneSyntaxError> {
+        let mut expect_operand = false;
+        let mut operator_position = 0;
+        let mut seen_operand = false;
+
+        loop {
+            self.skip_whitespace();
+            let position = self.position();
+            match self.peek() {
+                None => {
+                    if let Some(group) = group {
+                        return Err(self.error(group, "unclosed parenthesis"));
+                    }
+                    break;
+                }
+                Some(')') => {
+                    if group.is_none() {
+                        return Err(self.error(position, "unmatched closing parenthesis"));
+                    }
+                    if !seen_operand {
+                        return Err(self.error(position, "empty group"));
+                    }
+                    break;
+                }
+                _ => {}
+            }
+
+            let word = self.peek_word();
+            if matches!(word.as_str(), "AND" | "OR" | "&&" | "||") {
+                if !seen_operand || expect_operand {
+                    return Err(self.error(position, format!("unexpected operator {}", word)));
+                }
+                self.index += word.chars().count();
+                expect_operand = true;
+                operator_position = position;
+                continue;
+            }
+
+            self.parse_clause()?;
+            seen_operand = true;
+            expect_operand = false;
+        }
+
+        if expect_operand {
+            return Err(self.error(operator_position, "operator is missing its right operand"));
+        }
+        Ok(())
+    }
+
+    /// Whitespace-delimited word at the cursor, without consuming it
+    fn peek_word(&self) -> String {
+        self.chars[self.index..]
+            .iter()
+            .map(|(_, c)| *c)
+            .take_while(|c| !c.is_whitespace() && *c != '(' && *c != ')')
+            .collect()
+    }
+
+    /// Parse `[+|-|!|NOT] [field:] primary modifiers`
+    fn parse_clause(&mut self) -> Result<(), LuceneSyntaxError> {
+        let position = self.position();
+        if matches!(self.peek(), Some('+' | '-' | '!')) {
+            self.index += 1;
+            return self.parse_operand(position);
+        }
+        if self.peek_word() == "NOT" {
+            self.index += 3;
+            self.skip_whitespace();
+            return self.parse_operand(position);
+        }
+        self.parse_field_or_primary()
+    }
+
+    /// Parse the operand of a prefix operator
+    fn parse_operand(&mut self, operator: usize) -> Result<(), LuceneSyntaxError> {
+        match self.peek() {
+            None | Some(')') => Err(self.error(operator, "operator is missing its operand")),
+            Some(c) if c.is_whitespace() => {
+                Err(self.error(operator, "operator is missing its operand"))
+            }
+            _ => self.parse_clause(),
+        }
+    }
+
+    fn parse_field_or_primary(&mut self) -> Result<(), LuceneSyntaxError> {
+        let start = self.index;
+        let position = self.position();
+        if self.peek().is_some_and(|c| !"([{\"/".contains(c)) {
+            let term = self.read_term()?;
+            if self.peek() == Some(':') {
+                if term.is_empty() {
+                    return Err(self.error(position, "missing field name"));
+                }
+                self.index += 1;
+                let after = self.position();
+                match self.peek() {
+                    None => return Err(self.error(after, "missing query after field")),
+                    Some(c) if c.is_whitespace() || c == ')' => {
+                        return Err(self.error(after, "missing query after field"))
+                    }
+                    _ => {}
+                }
+                return self.parse_primary();
+            }
+            self.index = start;
+        }
+        self.parse_primary()
+    }
+
+    fn parse_primary(&mut self) -> Result<(), LuceneSyntaxError> {
+        let position = self.position();
+        match self.peek() {
+            Some('(') => {
+                self.index += 1;
+                self.parse_sequence(Some(position))?;
+                // parse_sequence stops at the closing parenthesis
+                self.index += 1;
+            }
+            Some('"') => {
+                self.index += 1;
+                self.read_until('"', position, "unterminated phrase")?;
+            }
+            Some('/') => {
+                self.index += 1;
+                self.read_until('/', position, "unterminated regular expression")?;
+            }
+            Some('[' | '{') => self.parse_range(position)?,
+            _ => {
+                let term = self.read_term()?;
+                if term.is_empty() {
+                    let c = self.peek().unwrap_or(' ');
+                    return Err(self.error(position, format!("unexpected character '{}'", c)));
+                }
+                if term != "*" && (term.starts_with('*') || term.starts_with('?')) {
+                    return Err(self.error(position, "a term cannot start with a wildcard"));
+                }
+            }
+        }
+        self.parse_modifiers()
+    }
+
+    /// Read an unquoted term, honoring backslash escapes
+    fn read_term(&mut self) -> Result<String, LuceneSyntaxError> {
+        let mut term = String::new();
+        while let Some(c) = self.peek() {
+            if c.is_whitespace() || "()[]{}\"^~:/".contains(c) {
+                break;
+            }
+            if c == '\\' {
+                let position = self.position();
+                self.index += 1;
+                match self.peek() {
+                    Some(escaped) => {
+                        term.push('\\');
+                        term.push(escaped);
+                    }
+                    None => return Err(self.error(position, "dangling escape character")),
+                }
+            } else {
+                term.push(c);
+            }
+            self.index += 1;
+        }
+        Ok(term)
+    }
+
+    /// Consume characters up to and including an unescaped `end`
+    fn read_until(
+        &mut self,
+        end: char,
+        start: usize,
+        message: &str,
+    ) -> Result<(), LuceneSyntaxError> {
+        while let Some(c) = self.peek() {
+            self.index += 1;
+            if c == '\\' {
+                self.index += 1;
+            } else if c == end {
+                return Ok(());
+            }
+        }
+        Err(self.error(start, message))
+    }
+
+    /// Read a range bound: a quoted value, or anything up to whitespace or the
+    /// closing bracket, so that values such as `2024-01-01T10:00` need no escaping
+    fn read_range_bound(&mut self) -> Result<String, LuceneSyntaxError> {
+        let position = self.position();
+        if self.peek() == Some('"') {
+            let start = self.index;
+            self.index += 1;
+            self.read_until('"', position, "unterminated phrase")?;
+            return Ok(self.chars[start..self.index]
+                .iter()
+                .map(|(_, c)| *c)
+                .collect());
+        }
+        let mut bound = String::new();
+        while let Some(c) = self.peek() {
+            if c.is_whitespace() || c == ']' || c == '}' {
+                break;
+            }
+            if c == '\\' {
+                let escape = self.position();
+                self.index += 1;
+                match self.peek() {
+                    Some(escaped) => {
+                        bound.push('\\');
+                        bound.push(escaped);
+                    }
+                    None => return Err(self.error(escape, "dangling escape character")),
+                }
+            } else {
+                bound.push(c);
+            }
+            self.index += 1;
+        }
+        Ok(bound)
+    }
+
+    fn parse_range(&mut self, start: usize) -> Result<(), LuceneSyntaxError> {
+        self.index += 1;
+        self.skip_whitespace();
+        if self.read_range_bound()?.is_empty() {
+            return Err(self.error(self.position(), "missing lower bound in range"));
+        }
+        self.skip_whitespace();
+        if self.peek_word() != "TO" {
+            return Err(self.error(self.position(), "expected TO in range"));
+        }
+        self.index += 2;
+        self.skip_whitespace();
+        let term_position = self.position();
+        let upper = self.read_range_bound()?;
+        if upper.is_empty() {
+            return Err(self.error(term_position, "missing upper bound in range"));
+        }
+        self.skip_whitespace();
+        match self.peek() {
+            Some(']' | '}') => {
+                self.index += 1;
+                Ok(())
+            }
+            _ => Err(self.error(start, "unterminated range")),
+        }
+    }
+
+    /// Parse trailing `~[number]` and `^number` modifiers
+    fn parse_modifiers(&mut self) -> Result<(), LuceneSyntaxError> {
+        loop {
+            let position = self.position();
+            match self.peek() {
+                Some('~') => {
+                    self.index += 1;
+                    let number = self.read_number();
+                    if !number.is_empty() && number.parse::<f32>().is_err() {
+                        return Err(self.error(position, "invalid fuzzy or proximity distance"));
+                    }
+                }
+                Some('^') => {
+                    self.index += 1;
+                    let number = self.read_number();
+                    match number.parse::<f32>() {
+                        Ok(boost) if boost > 0.0 => {}
+                        _ => return Err(self.error(position, "boost must be a positive number")),
+                    }
+                }
+                _ => return Ok(()),
+            }
+        }
+    }
+
+    fn read_number(&mut self) -> String {
+        let mut number = String::new();
+        while let Some(c) = self.peek().filter(|c| c.is_ascii_digit() || *c == '.') {
+            number.push(c);
+            self.index += 1;
+        }
+        number
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn error_at(query: &str) -> (usize, String) {
+        let error = validate(query).unwrap_err();
+        (error.position, error.message)
+    }
+
+    #[test]
+    fn escape_makes_input_literal() {
+        assert_eq!(escape("a-b"), "a\\-b");
+        assert_eq!(escape("C++ (beta)"), "C\\+\\+ \\(beta\\)");
+        assert_eq!(escape("path/to\\file"), "path\\/to\\\\file");
+        assert_eq!(escape("AND this OR NOT that"), "\\AND this \\OR \\NOT that");
+        assert_eq!(escape("ANDROID"), "ANDROID");
+        assert_eq!(escape("naïve:café"), "naïve\\:café");
+
+        let hostile = "title:x OR (y AND -z) \"q\" [a TO b]^2 ~ * ? \\ /r/";
+        assert_eq!(validate(&escape(hostile)), Ok(()));
+    }
+
+    #[test]
+    fn prefix_and_regex_are_escaped_once() {
+        assert_eq!(LuceneQuery::prefix("a-b").to_string(), "a\\-b*");
+        assert_eq!(LuceneQuery::prefix("what?*").to_string(), "what\\?\\**");
+        assert_eq!(LuceneQuery::wildcard("a-b?c*").to_string(), "a\\-b?c*");
+
+        assert_eq!(LuceneQuery::regex("a/b").to_string(), "/a\\/b/");
+        assert_eq!(LuceneQuery::regex("a\\/b").to_string(), "/a\\/b/");
+        assert_eq!(LuceneQuery::regex("[0-9]+\\.").to_string(), "/[0-9]+\\./");
+        assert_eq!(
+            LuceneQuery::regex("trailing\\").to_string(),
+            "/trailing\\\\/"
+        );
+
+        for query in [
+            LuceneQuery::prefix("a-b"),
+            LuceneQuery::regex("a\\/b"),
+            LuceneQuery::regex("trailing\\"),
+        ] {
+            assert_eq!(validate(&query.to_string()), Ok(()), "{}", query);
+        }
+    }
+
+    #[test]
+    fn rendered_queries_validate() {
+        let query = LuceneQuery::term("wifi")
+            .in_field("tags")
+            .required()
+            .and(LuceneQuery::phrase("ocean \"view\"").boost(2.5))
+            .and(
+                LuceneQuery::fuzzy_with_distance("luxery", 1)
+                    .or(LuceneQuery::proximity("hotel airport", 5))
+                    .not(),
+            )
+            .and(
+                LuceneQuery::range(
+                    RangeBound::Inclusive("2024-01-01T10:00".to_string()),
+                    RangeBound::Unbounded,
+                )
+                .in_field("date"),
+            )
+            .and(LuceneQuery::term("spam").prohibited());
+        let rendered = query.to_string();
+        assert_eq!(
+            rendered,
+            "+tags:wifi AND \"ocean \\\"view\\\"\"^2.5 AND NOT (luxery~1 OR \"hotel airport\"~5) \
+             AND date:[2024\\-01\\-01T10\\:00 TO *] AND -spam"
+        );
+        assert_eq!(validate(&rendered), Ok(()));
+
+        let range = LuceneQuery::range(
+            RangeBound::Exclusive("a".to_string()),
+            RangeBound::Inclusive("b]".to_string()),
+        );
+        assert_eq!(range.to_string(), "{a TO b\\]]");
+        assert_eq!(validate(&range.to_string()), Ok(()));
+
+        let range = LuceneQuery::range(
+            RangeBound::Inclusive("New York".to_string()),
+            RangeBound::Exclusive("say \"hi\"\tthere".to_string()),
+        );
+        assert_eq!(
+            range.to_string(),
+            "[\"New York\" TO \"say \\\"hi\\\"\tthere\"}"
+        );
+        assert_eq!(validate(&range.to_string()), Ok(()));
+        let range = LuceneQuery::range(RangeBound::Inclusive(String::new()), RangeBound::Unbounded);
+        assert_eq!(range.to_string(), "[\"\" TO *]");
+        assert_eq!(validate(&range.to_string()), Ok(()));
+        assert_eq!(
+            String::from(LuceneQuery::raw("a OR b").and(LuceneQuery::term("c"))),
+            "(a OR b) AND c"
+        );
+    }
+
+    #[test]
+    fn ranges_accept_unescaped_values() {
+        for query in [
+            "date:[2024-01-01T10:00 TO *]",
+            "date:{2024-01-01T10:00:00Z TO 2024-12-31T00:00:00Z}",
+            "price:[* TO 100]",
+            "name:[\"a b\" TO \"c d\"]",
+            "url:[http://a TO http://b]",
+        ] {
+            assert_eq!(validate(query), Ok(()), "{}", query);
+        }
+    }
+
+    #[test]
+    fn errors_report_byte_offsets() {
+        let cases = [
+            ("(a OR b", 0, "unclosed parenthesis"),
+            ("a OR b)", 6, "unmatched closing parenthesis"),
+            ("a ()", 3, "empty group"),
+            ("AND a", 0, "unexpected operator AND"),
+            ("a AND OR b", 6, "unexpected operator OR"),
+            ("a OR", 2, "operator is missing its right operand"),
+            ("a + b", 2, "operator is missing its operand"),
+            (":a", 0, "missing field name"),
+            ("title: a", 6, "missing query after field"),
+            ("\"open", 0, "unterminated phrase"),
+            ("a /re", 2, "unterminated regular expression"),
+            ("*a", 0, "a term cannot start with a wildcard"),
+            ("a\\", 1, "dangling escape character"),
+            ("[a b]", 3, "expected TO in range"),
+            ("[a TO ]", 6, "missing upper bound in range"),
+            ("x [a TO b", 2, "unterminated range"),
+            ("a^0", 1, "boost must be a positive number"),
+            ("a^", 1, "boost must be a positive number"),
+            ("a~1.2.3", 1, "invalid fuzzy or proximity distance"),
+            ("a ]", 2, "unexpected character ']'"),
+        ];
+        for (query, position, message) in cases {
+            assert_eq!(
+                error_at(query),
+                (position, message.to_string()),
+                "{}",
+                query
+            );
+        }
+
+        // Offsets count bytes, not characters
+        assert_eq!(error_at("café AND").0, 6);
+        let error = validate("é)").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "unmatched closing parenthesis at position 2"
+        );
+    }
+}
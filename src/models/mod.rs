@@ -1,6 +1,38 @@
 //! Data models for Azure AI Search operations
 
+pub mod analysis;
+pub mod analyze;
+pub mod autocomplete;
+pub mod debug;
+pub mod document;
+pub mod facet;
+pub mod filter;
+pub mod index;
+pub mod lucene;
+pub mod scoring;
 pub mod search;
+pub mod semantic;
+pub mod semantic_search;
+pub mod stats;
+pub mod suggest;
+pub mod vector;
+pub mod vector_search;
 
 /// Re-export commonly used models
+pub use analysis::*;
+pub use analyze::*;
+pub use autocomplete::*;
+pub use debug::*;
+pub use document::*;
+pub use facet::*;
+pub use filter::*;
+pub use index::*;
+pub use lucene::{LuceneQuery, LuceneSyntaxError, RangeBound};
+pub use scoring::*;
 pub use search::*;
+pub use semantic::*;
+pub use semantic_search::*;
+pub use stats::*;
+pub use suggest::*;
+pub use vector::*;
+pub use vector_search::*;
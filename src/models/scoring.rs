@@ -0,0 +1,180 @@
+use std::fmt;
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+
+use super::filter::GeoPoint;
+
+/// Value passed to a scoring profile function, sent as `name-value1,value2`
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(into = "String", try_from = "String")]
+pub struct ScoringParameter {
+    /// Parameter name referenced by the scoring function
+    pub name: String,
+    /// Parameter values, e.g. the tags of a tag function
+    pub values: Vec<String>,
+}
+
+impl ScoringParameter {
+    /// Parameter with a list of values (e.g. tags)
+    pub fn new<I, S>(name: impl Into<String>, values: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: ToString,
+    {
+        Self {
+            name: name.into(),
+            values: values.into_iter().map(|value| value.to_string()).collect(),
+        }
+    }
+
+    /// Reference point of a distance function, sent as `name-longitude,latitude`
+    /// (`name--122.2,44.8` for a negative longitude)
+    pub fn geo_point(name: impl Into<String>, point: GeoPoint) -> Self {
+        Self::new(name, [point.longitude, point.latitude])
+    }
+
+    /// Whether the name can be told apart from the values once rendered
+    pub(crate) fn has_valid_name(&self) -> bool {
+        !self.name.is_empty() && !self.name.contains('-')
+    }
+}
+
+impl fmt::Display for ScoringParameter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}-", self.name)?;
+        for (position, value) in self.values.iter().enumerate() {
+            if position > 0 {
+                f.write_str(",")?;
+            }
+            // Values containing commas or quotes are wrapped in single quotes
+            if value.contains([',', '\'']) {
+                write!(f, "'{}'", value.replace('\'', "''"))?;
+            } else {
+                f.write_str(value)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for ScoringParameter {
+    type Err = String;
+
+    /// Parse `name-value1,value2`
+    ///
+    /// Parameter names can't contain `-`, so the name ends at the first one and
+    /// everything after it is a value. A geo point with a negative longitude
+    /// therefore reads `name--122.2,44.8`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (name, values) = s
+            .split_once('-')
+            .ok_or_else(|| format!("missing scoring parameter values: {}", s))?;
+        if name.is_empty() {
+            return Err(format!("missing scoring parameter name: {}", s));
+        }
+        let mut parsed = Vec::new();
+        let mut chars = values.chars().peekable();
+        loop {
+            let mut value = String::new();
+            if chars.peek() == Some(&'\'') {
+                chars.next();
+                loop {
+                    match chars.next() {
+                        Some('\'') if chars.peek() == Some(&'\'') => {
+                            chars.next();
+                            value.push('\'');
+                        }
+                        Some('\'') => break,
+                        Some(c) => value.push(c),
+                        None => return Err(format!("unterminated quoted value: {}", s)),
+                    }
+                }
+            }
+            while let Some(c) = chars.next_if(|c| *c != ',') {
+                value.push(c);
+            }
+            parsed.push(value);
+            if chars.next().is_none() {
+                break;
+            }
+        }
+        Ok(Self {
+            name: name.to_string(),
+            values: parsed,
+        })
+    }
+}
+
+impl From<ScoringParameter> for String {
+    fn from(parameter: ScoringParameter) -> Self {
+        parameter.to_string()
+    }
+}
+
+impl TryFrom<String> for ScoringParameter {
+    type Error = String;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+/// Scope of the document statistics used for scoring
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum ScoringStatistics {
+    /// Statistics of the shard the document is in (faster)
+    Local,
+    /// Statistics of the whole index (more consistent)
+    Global,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn round_trip(parameter: &ScoringParameter) -> ScoringParameter {
+        parameter.to_string().parse().unwrap()
+    }
+
+    #[test]
+    fn geo_points_round_trip() {
+        let parameter = ScoringParameter::geo_point("mylocation", GeoPoint::new(-122.2, 44.8));
+        assert_eq!(parameter.to_string(), "mylocation--122.2,44.8");
+        assert_eq!(round_trip(&parameter), parameter);
+
+        let parameter = ScoringParameter::geo_point("here", GeoPoint::new(2.35, -48.85));
+        assert_eq!(parameter.to_string(), "here-2.35,-48.85");
+        assert_eq!(round_trip(&parameter), parameter);
+    }
+
+    #[test]
+    fn multi_value_parameters_round_trip() {
+        let parameter = ScoringParameter::new("tags", ["wifi", "sea-view", "a,b", "it's", ""]);
+        assert_eq!(parameter.to_string(), "tags-wifi,sea-view,'a,b','it''s',");
+        assert_eq!(round_trip(&parameter), parameter);
+
+        let parameter = ScoringParameter::new("boost", [-1]);
+        assert_eq!(parameter.to_string(), "boost--1");
+        assert_eq!(round_trip(&parameter), parameter);
+
+        let json = serde_json::to_value(&parameter).unwrap();
+        assert_eq!(json, "boost--1");
+        assert_eq!(
+            serde_json::from_value::<ScoringParameter>(json).unwrap(),
+            parameter
+        );
+    }
+
+    #[test]
+    fn malformed_parameters_are_rejected() {
+        assert!("tags".parse::<ScoringParameter>().is_err());
+        assert!("-wifi".parse::<ScoringParameter>().is_err());
+        assert!("tags-'open".parse::<ScoringParameter>().is_err());
+
+        assert!(ScoringParameter::new("tags", ["x"]).has_valid_name());
+        assert!(!ScoringParameter::new("my-tags", ["x"]).has_valid_name());
+        assert!(!ScoringParameter::new("", ["x"]).has_valid_name());
+    }
+}
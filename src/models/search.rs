@@ -1,6 +1,21 @@
+use std::collections::HashMap;
+
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+use super::debug::{
+    DocumentDebugInfo, FeaturesMode, FieldFeatures, QueryDebugMode, SearchDebugInfo,
+};
+use super::facet::{FacetSpec, Facets};
+use super::lucene;
+use super::scoring::{ScoringParameter, ScoringStatistics};
+use super::semantic::{
+    Caption, QueryAnswer, QueryCaption, SemanticAnswer, SemanticErrorMode,
+    SemanticPartialResponseReason, SemanticPartialResponseType,
+};
+use super::vector::{VectorFilterMode, VectorQuery};
+use crate::error::ValidationError;
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
 #[serde(rename_all = "lowercase")]
 pub enum QueryType {
     /// simple
@@ -11,7 +26,7 @@ pub enum QueryType {
     Semantic,
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
 #[serde(rename_all = "lowercase")]
 pub enum SearchMode {
     /// any
@@ -24,10 +39,12 @@ pub enum SearchMode {
 #[derive(Debug, Serialize, Deserialize, Clone, Default)]
 #[serde(rename_all = "camelCase")]
 pub struct SearchRequest {
+    #[serde(default)]
     pub count: bool,
     /// Select fields
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(serialize_with = "serialize_option_vec_as_string")]
+    #[serde(default, deserialize_with = "deserialize_option_vec_from_string")]
     pub select: Option<Vec<String>>,
     /// Search text
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -35,6 +52,7 @@ pub struct SearchRequest {
     /// Search fields
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(serialize_with = "serialize_option_vec_as_string")]
+    #[serde(default, deserialize_with = "deserialize_option_vec_from_string")]
     pub search_fields: Option<Vec<String>>,
     /// Search mode
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -42,18 +60,50 @@ pub struct SearchRequest {
     /// Filter expression
     #[serde(skip_serializing_if = "Option::is_none")]
     pub filter: Option<String>,
-    /// Facets to include
+    /// Facet expressions, see [`super::facet::FacetSpec`]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub facets: Option<Vec<String>>,
     /// Vector query for vector search
+    ///
+    /// `VectorKind` queries convert with `.into()`.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub vector_queries: Option<Vec<VectorKind>>,
+    pub vector_queries: Option<Vec<VectorQuery>>,
+    /// When the filter is applied relative to the vector search
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub vector_filter_mode: Option<VectorFilterMode>,
     /// Semantic configuration
     #[serde(skip_serializing_if = "Option::is_none")]
     pub semantic_configuration: Option<String>,
+    /// Extractive answers to return with a semantic query
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub answers: Option<QueryAnswer>,
+    /// Extractive captions to return with a semantic query
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub captions: Option<QueryCaption>,
+    /// Query used for semantic ranking instead of `search`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub semantic_query: Option<String>,
+    /// Time to wait for semantic ranking before returning partial results
+    #[serde(
+        rename = "semanticMaxWaitInMilliseconds",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub semantic_max_wait_in_milliseconds: Option<u32>,
+    /// What happens when semantic ranking fails
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub semantic_error_handling: Option<SemanticErrorMode>,
     /// Query type
     #[serde(skip_serializing_if = "Option::is_none")]
     pub query_type: Option<QueryType>,
+    /// Scoring profile used to rank results
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scoring_profile: Option<String>,
+    /// Values passed to the scoring profile functions
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scoring_parameters: Option<Vec<ScoringParameter>>,
+    /// Scope of the document statistics used for scoring
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scoring_statistics: Option<ScoringStatistics>,
     /// Orderby_expression
     #[serde(skip_serializing_if = "Option::is_none")]
     pub orderby: Option<String>,
@@ -63,6 +113,7 @@ pub struct SearchRequest {
     /// highlight fields
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(serialize_with = "serialize_option_vec_as_string")]
+    #[serde(default, deserialize_with = "deserialize_option_vec_from_string")]
     pub highlight: Option<Vec<String>>,
     /// Highlight pre/post tags
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -72,14 +123,333 @@ pub struct SearchRequest {
     /// Session id
     #[serde(skip_serializing_if = "Option::is_none")]
     pub session_id: Option<String>,
+    /// Debugging information to return (preview)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub debug: Option<QueryDebugMode>,
+    /// Whether to return per-field ranking features (preview)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub features_mode: Option<FeaturesMode>,
     /// Top k results to return
+    ///
+    /// At most [`MAX_TOP`]; to read more results, stream them with
+    /// [`crate::operations::search::SearchTrait::search_pages`] and
+    /// [`SearchPagingOptions::max_results`].
     #[serde(skip_serializing_if = "Option::is_none")]
     pub top: Option<u16>,
     /// Skip first n results
+    ///
+    /// A `u32` so that it can hold the service maximum of [`MAX_SKIP`], which
+    /// `@search.nextPageParameters` may reach.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub skip: Option<u16>,
+    pub skip: Option<u32>,
+}
+
+impl SearchRequest {
+    /// Create a builder for a validated search request
+    pub fn builder() -> SearchRequestBuilder {
+        SearchRequestBuilder::default()
+    }
+
+    /// Check the request against rules the service would reject it for
+    pub fn validate(&self) -> Result<(), ValidationError> {
+        if self.query_type == Some(QueryType::Semantic) && self.semantic_configuration.is_none() {
+            return Err(ValidationError::SemanticConfigurationRequired);
+        }
+        if self.query_type == Some(QueryType::Full) {
+            if let Some(search) = &self.search {
+                lucene::validate(search).map_err(ValidationError::InvalidQuerySyntax)?;
+            }
+        }
+        if self.highlight_pre_tag.is_some() || self.highlight_post_tag.is_some() {
+            if self.highlight.as_deref().unwrap_or_default().is_empty() {
+                return Err(ValidationError::HighlightTagsWithoutHighlight);
+            }
+            if self.highlight_pre_tag.is_none() || self.highlight_post_tag.is_none() {
+                return Err(ValidationError::IncompleteHighlightTags);
+            }
+        }
+        if let Some(top) = self.top {
+            if u32::from(top) > MAX_TOP {
+                return Err(ValidationError::TopTooLarge {
+                    top: top.into(),
+                    max: MAX_TOP,
+                });
+            }
+        }
+        if let Some(skip) = self.skip {
+            if skip > MAX_SKIP {
+                return Err(ValidationError::SkipTooLarge {
+                    skip,
+                    max: MAX_SKIP,
+                });
+            }
+        }
+        if let Some(minimum_coverage) = self.minimum_coverage {
+            if minimum_coverage > 100 {
+                return Err(ValidationError::InvalidMinimumCoverage(minimum_coverage));
+            }
+        }
+        if let Some(parameter) = self
+            .scoring_parameters
+            .iter()
+            .flatten()
+            .find(|parameter| !parameter.has_valid_name())
+        {
+            return Err(ValidationError::InvalidScoringParameterName(
+                parameter.name.clone(),
+            ));
+        }
+        for (position, query) in self.vector_queries.iter().flatten().enumerate() {
+            if query.k == Some(0) || query.fields.is_empty() {
+                return Err(ValidationError::InvalidVectorQuery { position });
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Maximum number of results the service returns in a single response
+pub const MAX_TOP: u32 = 1000;
+
+/// Maximum number of results that can be skipped
+pub const MAX_SKIP: u32 = 100_000;
+
+/// Fluent builder for [`SearchRequest`]
+#[derive(Debug, Clone, Default)]
+pub struct SearchRequestBuilder {
+    request: SearchRequest,
+    /// Facets added with [`SearchRequestBuilder::facet_spec`], checked in `build`
+    facet_specs: Vec<FacetSpec>,
 }
 
+impl SearchRequestBuilder {
+    /// Set the search text
+    pub fn search(mut self, search: impl Into<String>) -> Self {
+        self.request.search = Some(search.into());
+        self
+    }
+
+    /// Request the total count of matching documents
+    pub fn count(mut self, count: bool) -> Self {
+        self.request.count = count;
+        self
+    }
+
+    /// Set the fields to return
+    pub fn select<I, S>(mut self, fields: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.request.select = Some(fields.into_iter().map(Into::into).collect());
+        self
+    }
+
+    /// Set the fields to search in
+    pub fn search_fields<I, S>(mut self, fields: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.request.search_fields = Some(fields.into_iter().map(Into::into).collect());
+        self
+    }
+
+    /// Set the search mode
+    pub fn search_mode(mut self, search_mode: SearchMode) -> Self {
+        self.request.search_mode = Some(search_mode);
+        self
+    }
+
+    /// Set the filter expression
+    pub fn filter(mut self, filter: impl Into<String>) -> Self {
+        self.request.filter = Some(filter.into());
+        self
+    }
+
+    /// Add a facet expression, sent as is
+    pub fn facet(mut self, facet: impl Into<String>) -> Self {
+        self.request
+            .facets
+            .get_or_insert_with(Vec::new)
+            .push(facet.into());
+        self
+    }
+
+    /// Add a [`FacetSpec`], checked with [`FacetSpec::validate`] when the
+    /// request is built
+    pub fn facet_spec(mut self, facet: FacetSpec) -> Self {
+        self.request
+            .facets
+            .get_or_insert_with(Vec::new)
+            .push(facet.to_string());
+        self.facet_specs.push(facet);
+        self
+    }
+
+    /// Add a vector query
+    pub fn vector_query(mut self, query: impl Into<VectorQuery>) -> Self {
+        self.request
+            .vector_queries
+            .get_or_insert_with(Vec::new)
+            .push(query.into());
+        self
+    }
+
+    /// Add several vector queries
+    pub fn vector_queries(
+        mut self,
+        queries: impl IntoIterator<Item = impl Into<VectorQuery>>,
+    ) -> Self {
+        self.request
+            .vector_queries
+            .get_or_insert_with(Vec::new)
+            .extend(queries.into_iter().map(Into::into));
+        self
+    }
+
+    /// Set when the filter is applied relative to the vector search
+    pub fn vector_filter_mode(mut self, mode: VectorFilterMode) -> Self {
+        self.request.vector_filter_mode = Some(mode);
+        self
+    }
+
+    /// Set the semantic configuration
+    pub fn semantic_configuration(mut self, semantic_configuration: impl Into<String>) -> Self {
+        self.request.semantic_configuration = Some(semantic_configuration.into());
+        self
+    }
+
+    /// Request extractive answers
+    pub fn answers(mut self, answers: QueryAnswer) -> Self {
+        self.request.answers = Some(answers);
+        self
+    }
+
+    /// Request extractive captions
+    pub fn captions(mut self, captions: QueryCaption) -> Self {
+        self.request.captions = Some(captions);
+        self
+    }
+
+    /// Set the query used for semantic ranking
+    pub fn semantic_query(mut self, semantic_query: impl Into<String>) -> Self {
+        self.request.semantic_query = Some(semantic_query.into());
+        self
+    }
+
+    /// Set how long to wait for semantic ranking
+    pub fn semantic_max_wait(mut self, max_wait: std::time::Duration) -> Self {
+        self.request.semantic_max_wait_in_milliseconds =
+            Some(max_wait.as_millis().try_into().unwrap_or(u32::MAX));
+        self
+    }
+
+    /// Set what happens when semantic ranking fails
+    pub fn semantic_error_handling(mut self, mode: SemanticErrorMode) -> Self {
+        self.request.semantic_error_handling = Some(mode);
+        self
+    }
+
+    /// Set the query type
+    pub fn query_type(mut self, query_type: QueryType) -> Self {
+        self.request.query_type = Some(query_type);
+        self
+    }
+
+    /// Set the scoring profile
+    pub fn scoring_profile(mut self, scoring_profile: impl Into<String>) -> Self {
+        self.request.scoring_profile = Some(scoring_profile.into());
+        self
+    }
+
+    /// Add a scoring profile parameter
+    pub fn scoring_parameter(mut self, parameter: ScoringParameter) -> Self {
+        self.request
+            .scoring_parameters
+            .get_or_insert_with(Vec::new)
+            .push(parameter);
+        self
+    }
+
+    /// Set the scope of the document statistics used for scoring
+    pub fn scoring_statistics(mut self, scoring_statistics: ScoringStatistics) -> Self {
+        self.request.scoring_statistics = Some(scoring_statistics);
+        self
+    }
+
+    /// Set the orderby expression
+    pub fn orderby(mut self, orderby: impl Into<String>) -> Self {
+        self.request.orderby = Some(orderby.into());
+        self
+    }
+
+    /// Set the % of the index that must be covered
+    pub fn minimum_coverage(mut self, minimum_coverage: u8) -> Self {
+        self.request.minimum_coverage = Some(minimum_coverage);
+        self
+    }
+
+    /// Set the fields to highlight
+    pub fn highlight<I, S>(mut self, fields: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.request.highlight = Some(fields.into_iter().map(Into::into).collect());
+        self
+    }
+
+    /// Set the highlight pre/post tags
+    pub fn highlight_tags(
+        mut self,
+        pre_tag: impl Into<String>,
+        post_tag: impl Into<String>,
+    ) -> Self {
+        self.request.highlight_pre_tag = Some(pre_tag.into());
+        self.request.highlight_post_tag = Some(post_tag.into());
+        self
+    }
+
+    /// Set the session id
+    pub fn session_id(mut self, session_id: impl Into<String>) -> Self {
+        self.request.session_id = Some(session_id.into());
+        self
+    }
+
+    /// Request debugging information (preview)
+    pub fn debug(mut self, debug: QueryDebugMode) -> Self {
+        self.request.debug = Some(debug);
+        self
+    }
+
+    /// Request per-field ranking features (preview)
+    pub fn features_mode(mut self, features_mode: FeaturesMode) -> Self {
+        self.request.features_mode = Some(features_mode);
+        self
+    }
+
+    /// Set the number of results to return
+    pub fn top(mut self, top: u16) -> Self {
+        self.request.top = Some(top);
+        self
+    }
+
+    /// Set the number of results to skip
+    pub fn skip(mut self, skip: u32) -> Self {
+        self.request.skip = Some(skip);
+        self
+    }
+
+    /// Validate and build the request
+    pub fn build(self) -> Result<SearchRequest, ValidationError> {
+        self.facet_specs.iter().try_for_each(FacetSpec::validate)?;
+        self.request.validate()?;
+        Ok(self.request)
+    }
+}
+
+/// Untyped vector query, converts into [`VectorQuery`]
 #[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(untagged)]
 pub enum VectorKind {
@@ -89,6 +459,7 @@ pub enum VectorKind {
         vector: Vec<f32>,
         k: u16,
         #[serde(serialize_with = "serialize_vec_as_string")]
+        #[serde(deserialize_with = "deserialize_vec_from_string")]
         fields: Vec<String>,
         #[serde(skip_serializing_if = "Option::is_none")]
         weight: Option<f32>,
@@ -99,6 +470,7 @@ pub enum VectorKind {
         text: String,
         k: u16,
         #[serde(serialize_with = "serialize_vec_as_string")]
+        #[serde(deserialize_with = "deserialize_vec_from_string")]
         fields: Vec<String>,
         #[serde(skip_serializing_if = "Option::is_none")]
         weight: Option<f32>,
@@ -114,6 +486,24 @@ pub struct SearchResult<T> {
     /// Document highlights (if requested)
     #[serde(rename = "@search.highlights", skip_serializing_if = "Option::is_none")]
     pub highlights: Option<serde_json::Value>,
+    /// Semantic reranker score (semantic queries only)
+    #[serde(
+        rename = "@search.rerankerScore",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub reranker_score: Option<f64>,
+    /// Semantic captions (if requested)
+    #[serde(rename = "@search.captions", skip_serializing_if = "Option::is_none")]
+    pub captions: Option<Vec<Caption>>,
+    /// Ranking features of each searched field (if requested)
+    #[serde(rename = "@search.features", skip_serializing_if = "Option::is_none")]
+    pub features: Option<HashMap<String, FieldFeatures>>,
+    /// Document debugging information (if requested)
+    #[serde(
+        rename = "@search.documentDebugInfo",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub document_debug_info: Option<DocumentDebugInfo>,
     /// The document itself
     #[serde(flatten)]
     pub document: T,
@@ -124,19 +514,114 @@ pub struct SearchResult<T> {
 pub struct SearchResponse<T> {
     /// Search results
     pub value: Vec<SearchResult<T>>,
-    /// Facet results
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub facets: Option<serde_json::Value>,
-    /// Semantic answers
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub answers: Option<serde_json::Value>,
+    /// Facet results keyed by field name
+    #[serde(
+        rename = "@search.facets",
+        alias = "facets",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub facets: Option<Facets>,
+    /// Semantic answers (if requested)
+    #[serde(
+        rename = "@search.answers",
+        alias = "answers",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub answers: Option<Vec<SemanticAnswer>>,
+    /// Why semantic ranking returned partial results
+    #[serde(
+        rename = "@search.semanticPartialResponseReason",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub semantic_partial_response_reason: Option<SemanticPartialResponseReason>,
+    /// Which results a partial semantic response contains
+    #[serde(
+        rename = "@search.semanticPartialResponseType",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub semantic_partial_response_type: Option<SemanticPartialResponseType>,
     /// Count of total results
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "@odata.count",
+        alias = "count",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub count: Option<u64>,
+    /// % of the index covered by the query, when `minimum_coverage` was set
+    #[serde(rename = "@search.coverage", skip_serializing_if = "Option::is_none")]
+    pub coverage: Option<f64>,
+    /// Query debugging information (if requested)
+    #[serde(rename = "@search.debug", skip_serializing_if = "Option::is_none")]
+    pub debug: Option<SearchDebugInfo>,
+    /// URL of the next page, when the service could not return all requested results
+    #[serde(rename = "@odata.nextLink", skip_serializing_if = "Option::is_none")]
+    pub next_link: Option<String>,
+    /// Request to send for the next page of results
+    #[serde(
+        rename = "@search.nextPageParameters",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub next_page_parameters: Option<SearchRequest>,
+    /// Set by [`CoveragePolicy::Warn`] when coverage is below `minimum_coverage`
+    #[serde(skip)]
+    pub coverage_warning: Option<CoverageWarning>,
+}
+
+impl<T> SearchResponse<T> {
+    /// Whether the reported coverage is below `minimum_coverage`
+    pub fn is_coverage_below(&self, minimum_coverage: u8) -> bool {
+        self.coverage
+            .is_some_and(|coverage| coverage < f64::from(minimum_coverage))
+    }
+}
+
+/// What the client does when a response covers less of the index than
+/// the request's `minimum_coverage`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CoveragePolicy {
+    /// Return the response unchanged
+    #[default]
+    Ignore,
+    /// Return the response with [`SearchResponse::coverage_warning`] set
+    Warn,
+    /// Fail with [`crate::Error::InsufficientCoverage`]
+    Error,
+}
+
+/// Coverage of a response that fell short of the requested minimum
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CoverageWarning {
+    /// % of the index covered by the query
+    pub coverage: f64,
+    /// Requested minimum %
+    pub minimum_coverage: u8,
+}
+
+/// Options for streaming search results across pages
+#[derive(Debug, Clone, Default)]
+pub struct SearchPagingOptions {
+    /// Request the next page while the current one is being consumed
+    pub prefetch: bool,
+    /// Stop after this many results in total
+    pub max_results: Option<usize>,
+}
+
+impl SearchPagingOptions {
+    /// Enable or disable prefetching of the next page
+    pub fn prefetch(mut self, prefetch: bool) -> Self {
+        self.prefetch = prefetch;
+        self
+    }
+
+    /// Stop after this many results in total
+    pub fn max_results(mut self, max_results: usize) -> Self {
+        self.max_results = Some(max_results);
+        self
+    }
 }
 
 /// Serialize a vector as a string
-fn serialize_vec_as_string<S>(vec: &[String], serializer: S) -> Result<S::Ok, S::Error>
+pub(crate) fn serialize_vec_as_string<S>(vec: &[String], serializer: S) -> Result<S::Ok, S::Error>
 where
     S: serde::Serializer,
 {
@@ -145,7 +630,7 @@ where
 }
 
 /// Serialize a option vector as a string
-fn serialize_option_vec_as_string<S>(
+pub(crate) fn serialize_option_vec_as_string<S>(
     vec: &Option<Vec<String>>,
     serializer: S,
 ) -> Result<S::Ok, S::Error>
@@ -155,3 +640,199 @@ where
     let joined = vec.as_ref().unwrap_or(&vec![]).join(",");
     serializer.serialize_str(&joined)
 }
+
+/// Comma separated list or array of strings
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum StringList {
+    Joined(String),
+    List(Vec<String>),
+}
+
+impl From<StringList> for Vec<String> {
+    fn from(list: StringList) -> Self {
+        match list {
+            StringList::Joined(joined) => joined
+                .split(',')
+                .map(str::trim)
+                .filter(|item| !item.is_empty())
+                .map(str::to_string)
+                .collect(),
+            StringList::List(list) => list,
+        }
+    }
+}
+
+/// Deserialize a vector from a comma separated string
+pub(crate) fn deserialize_vec_from_string<'de, D>(deserializer: D) -> Result<Vec<String>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    StringList::deserialize(deserializer).map(Into::into)
+}
+
+/// Deserialize an option vector from a comma separated string
+pub(crate) fn deserialize_option_vec_from_string<'de, D>(
+    deserializer: D,
+) -> Result<Option<Vec<String>>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    Option::<StringList>::deserialize(deserializer).map(|list| list.map(Into::into))
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn builder_serializes_request() {
+        let request = SearchRequest::builder()
+            .search("luxury")
+            .select(["id", "name"])
+            .search_fields(["description"])
+            .highlight(["description"])
+            .highlight_tags("<b>", "</b>")
+            .top(500)
+            .skip(70_000)
+            .count(true)
+            .build()
+            .unwrap();
+
+        assert_eq!(
+            serde_json::to_value(&request).unwrap(),
+            json!({
+                "count": true,
+                "search": "luxury",
+                "select": "id,name",
+                "searchFields": "description",
+                "highlight": "description",
+                "highlightPreTag": "<b>",
+                "highlightPostTag": "</b>",
+                "top": 500,
+                "skip": 70000,
+            })
+        );
+    }
+
+    #[test]
+    fn request_deserializes_from_next_page_parameters() {
+        let request: SearchRequest = serde_json::from_value(json!({
+            "search": "*",
+            "select": "id, name",
+            "highlight": ["description"],
+            "skip": 99000,
+            "top": 1000,
+        }))
+        .unwrap();
+        assert_eq!(
+            request.select,
+            Some(vec!["id".to_string(), "name".to_string()])
+        );
+        assert_eq!(request.highlight, Some(vec!["description".to_string()]));
+        assert_eq!(request.skip, Some(99_000));
+        assert_eq!(request.top, Some(1000));
+    }
+
+    #[test]
+    fn semantic_query_needs_configuration() {
+        let err = SearchRequest::builder()
+            .query_type(QueryType::Semantic)
+            .build()
+            .unwrap_err();
+        assert_eq!(err, ValidationError::SemanticConfigurationRequired);
+
+        assert!(SearchRequest::builder()
+            .query_type(QueryType::Semantic)
+            .semantic_configuration("default")
+            .build()
+            .is_ok());
+    }
+
+    #[test]
+    fn highlight_tags_need_fields() {
+        let err = SearchRequest::builder()
+            .highlight_tags("<b>", "</b>")
+            .build()
+            .unwrap_err();
+        assert_eq!(err, ValidationError::HighlightTagsWithoutHighlight);
+
+        let err = SearchRequest::builder()
+            .highlight(Vec::<String>::new())
+            .highlight_tags("<b>", "</b>")
+            .build()
+            .unwrap_err();
+        assert_eq!(err, ValidationError::HighlightTagsWithoutHighlight);
+
+        let request = SearchRequest {
+            highlight: Some(vec!["description".to_string()]),
+            highlight_pre_tag: Some("<b>".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(
+            request.validate(),
+            Err(ValidationError::IncompleteHighlightTags)
+        );
+    }
+
+    #[test]
+    fn limits() {
+        assert!(SearchRequest::builder().top(1000).build().is_ok());
+        let err = SearchRequest::builder().top(1001).build().unwrap_err();
+        assert_eq!(
+            err,
+            ValidationError::TopTooLarge {
+                top: 1001,
+                max: MAX_TOP
+            }
+        );
+
+        let err = SearchRequest::builder().skip(100_001).build().unwrap_err();
+        assert_eq!(
+            err,
+            ValidationError::SkipTooLarge {
+                skip: 100_001,
+                max: MAX_SKIP
+            }
+        );
+
+        let err = SearchRequest::builder()
+            .minimum_coverage(101)
+            .build()
+            .unwrap_err();
+        assert_eq!(err, ValidationError::InvalidMinimumCoverage(101));
+    }
+
+    #[test]
+    fn scoring_parameter_names_are_checked() {
+        let request = SearchRequest::builder()
+            .scoring_parameter(ScoringParameter::new("my-tags", ["wifi"]))
+            .build();
+        assert_eq!(
+            request.unwrap_err(),
+            ValidationError::InvalidScoringParameterName("my-tags".to_string())
+        );
+        assert!(SearchRequest::builder()
+            .scoring_parameter(ScoringParameter::new("tags", ["wi-fi"]))
+            .build()
+            .is_ok());
+    }
+
+    #[test]
+    fn full_query_syntax_is_checked() {
+        let err = SearchRequest::builder()
+            .query_type(QueryType::Full)
+            .search("title:(luxury")
+            .build()
+            .unwrap_err();
+        assert!(matches!(err, ValidationError::InvalidQuerySyntax(_)));
+
+        // Simple queries are not parsed as Lucene
+        assert!(SearchRequest::builder()
+            .search("title:(luxury")
+            .build()
+            .is_ok());
+    }
+}
@@ -0,0 +1,265 @@
+use std::fmt;
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+
+/// Extractive answers requested with a semantic query, sent as
+/// `extractive|count-3,threshold-0.7`
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
+#[serde(into = "String", try_from = "String")]
+pub struct QueryAnswer {
+    /// Number of answers to return (default 1)
+    pub count: Option<u32>,
+    /// Minimum confidence of returned answers (0 to 1, default 0.7)
+    pub threshold: Option<f64>,
+}
+
+impl QueryAnswer {
+    /// Request extractive answers
+    pub fn extractive() -> Self {
+        Self::default()
+    }
+
+    /// Set the number of answers to return
+    pub fn count(mut self, count: u32) -> Self {
+        self.count = Some(count);
+        self
+    }
+
+    /// Set the minimum confidence of returned answers
+    pub fn threshold(mut self, threshold: f64) -> Self {
+        self.threshold = Some(threshold);
+        self
+    }
+}
+
+impl fmt::Display for QueryAnswer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("extractive")?;
+        let options: Vec<String> = [
+            self.count.map(|count| format!("count-{}", count)),
+            self.threshold
+                .map(|threshold| format!("threshold-{}", threshold)),
+        ]
+        .into_iter()
+        .flatten()
+        .collect();
+        if !options.is_empty() {
+            write!(f, "|{}", options.join(","))?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for QueryAnswer {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (kind, options) = s.split_once('|').unwrap_or((s, ""));
+        if kind != "extractive" {
+            return Err(format!("unsupported answer type: {}", kind));
+        }
+        let mut answer = QueryAnswer::extractive();
+        for option in options.split(',').filter(|option| !option.is_empty()) {
+            match option.split_once('-') {
+                Some(("count", count)) => {
+                    answer.count = Some(count.parse().map_err(|_| option.to_string())?)
+                }
+                Some(("threshold", threshold)) => {
+                    answer.threshold = Some(threshold.parse().map_err(|_| option.to_string())?)
+                }
+                _ => return Err(format!("unsupported answer option: {}", option)),
+            }
+        }
+        Ok(answer)
+    }
+}
+
+impl From<QueryAnswer> for String {
+    fn from(answer: QueryAnswer) -> Self {
+        answer.to_string()
+    }
+}
+
+impl TryFrom<String> for QueryAnswer {
+    type Error = String;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+/// Extractive captions requested with a semantic query, sent as
+/// `extractive|highlight-true`
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq)]
+#[serde(into = "String", try_from = "String")]
+pub struct QueryCaption {
+    /// Whether to highlight key phrases in captions (default true)
+    pub highlight: Option<bool>,
+}
+
+impl QueryCaption {
+    /// Request extractive captions
+    pub fn extractive() -> Self {
+        Self::default()
+    }
+
+    /// Enable or disable highlighting in captions
+    pub fn highlight(mut self, highlight: bool) -> Self {
+        self.highlight = Some(highlight);
+        self
+    }
+}
+
+impl fmt::Display for QueryCaption {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("extractive")?;
+        if let Some(highlight) = self.highlight {
+            write!(f, "|highlight-{}", highlight)?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for QueryCaption {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (kind, options) = s.split_once('|').unwrap_or((s, ""));
+        if kind != "extractive" {
+            return Err(format!("unsupported caption type: {}", kind));
+        }
+        let mut caption = QueryCaption::extractive();
+        for option in options.split(',').filter(|option| !option.is_empty()) {
+            match option.split_once('-') {
+                Some(("highlight", highlight)) => {
+                    caption.highlight = Some(highlight.parse().map_err(|_| option.to_string())?)
+                }
+                _ => return Err(format!("unsupported caption option: {}", option)),
+            }
+        }
+        Ok(caption)
+    }
+}
+
+impl From<QueryCaption> for String {
+    fn from(caption: QueryCaption) -> Self {
+        caption.to_string()
+    }
+}
+
+impl TryFrom<String> for QueryCaption {
+    type Error = String;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+/// What happens when semantic ranking fails or times out
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum SemanticErrorMode {
+    /// Return the results ranked so far
+    Partial,
+    /// Fail the whole request
+    Fail,
+}
+
+/// Answer extracted from a top result
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SemanticAnswer {
+    /// Key of the document the answer was extracted from
+    pub key: String,
+    /// Answer text
+    pub text: String,
+    /// Answer text with key phrases highlighted
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub highlights: Option<String>,
+    /// Confidence of the answer
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub score: Option<f64>,
+}
+
+/// Caption extracted from a result
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct Caption {
+    /// Caption text
+    pub text: String,
+    /// Caption text with key phrases highlighted
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub highlights: Option<String>,
+}
+
+/// Why semantic ranking returned a partial response
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum SemanticPartialResponseReason {
+    /// `semanticMaxWaitInMilliseconds` was exceeded
+    MaxWaitExceeded,
+    /// The semantic ranker was overloaded
+    CapacityOverloaded,
+    /// Transient failure of the semantic ranker
+    Transient,
+    /// Reason not known to this version of the library
+    #[serde(other)]
+    Unknown,
+}
+
+/// Which results a partial semantic response contains
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum SemanticPartialResponseType {
+    /// Results without semantic reranking
+    BaseResults,
+    /// Results with semantic reranking
+    RerankedResults,
+    /// Type not known to this version of the library
+    #[serde(other)]
+    Unknown,
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn answers_and_captions_round_trip() {
+        let answer = QueryAnswer::extractive().count(3).threshold(0.7);
+        assert_eq!(answer.to_string(), "extractive|count-3,threshold-0.7");
+        assert_eq!(answer.to_string().parse::<QueryAnswer>().unwrap(), answer);
+        assert_eq!(
+            serde_json::to_value(QueryAnswer::extractive()).unwrap(),
+            json!("extractive")
+        );
+
+        let caption = QueryCaption::extractive().highlight(false);
+        assert_eq!(caption.to_string(), "extractive|highlight-false");
+        assert_eq!(
+            serde_json::from_value::<QueryCaption>(json!("extractive|highlight-false")).unwrap(),
+            caption
+        );
+    }
+
+    #[test]
+    fn malformed_answers_and_captions_are_rejected() {
+        assert!("generative".parse::<QueryAnswer>().is_err());
+        assert!("extractive|count-many".parse::<QueryAnswer>().is_err());
+        assert!("extractive|top-3".parse::<QueryAnswer>().is_err());
+        assert!("extractive|highlight-yes".parse::<QueryCaption>().is_err());
+        assert!(serde_json::from_value::<QueryCaption>(json!("none")).is_err());
+    }
+
+    #[test]
+    fn unknown_partial_response_values_are_tolerated() {
+        let reason: SemanticPartialResponseReason =
+            serde_json::from_value(json!("somethingNew")).unwrap();
+        assert_eq!(reason, SemanticPartialResponseReason::Unknown);
+        let kind: SemanticPartialResponseType =
+            serde_json::from_value(json!("rerankedResults")).unwrap();
+        assert_eq!(kind, SemanticPartialResponseType::RerankedResults);
+    }
+}
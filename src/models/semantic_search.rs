@@ -0,0 +1,163 @@
+use serde::{Deserialize, Serialize};
+
+/// Semantic ranking configuration of an index
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct SemanticSearch {
+    /// Configuration used when a semantic query doesn't name one
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_configuration: Option<String>,
+    /// Named configurations
+    #[serde(default)]
+    pub configurations: Vec<SemanticConfiguration>,
+}
+
+impl SemanticSearch {
+    /// Add a configuration
+    pub fn configuration(mut self, configuration: SemanticConfiguration) -> Self {
+        self.configurations.push(configuration);
+        self
+    }
+
+    /// Set the configuration used when a semantic query doesn't name one
+    pub fn default_configuration(mut self, name: impl Into<String>) -> Self {
+        self.default_configuration = Some(name.into());
+        self
+    }
+
+    /// Configuration with the given name
+    pub fn find_configuration(&self, name: &str) -> Option<&SemanticConfiguration> {
+        self.configurations
+            .iter()
+            .find(|configuration| configuration.name == name)
+    }
+}
+
+/// Fields the semantic ranker reads for a named configuration
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct SemanticConfiguration {
+    /// Configuration name, passed as `semanticConfiguration` in queries
+    pub name: String,
+    /// Fields used for ranking, captions and answers
+    pub prioritized_fields: SemanticPrioritizedFields,
+    /// Score used to order results (`BoostedRerankerScore` or `RerankerScore`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ranking_order: Option<String>,
+}
+
+impl SemanticConfiguration {
+    /// Create a configuration
+    pub fn new(name: impl Into<String>, prioritized_fields: SemanticPrioritizedFields) -> Self {
+        Self {
+            name: name.into(),
+            prioritized_fields,
+            ranking_order: None,
+        }
+    }
+}
+
+/// Fields passed to the semantic ranker, in order of priority
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct SemanticPrioritizedFields {
+    /// Short title of the document
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title_field: Option<SemanticField>,
+    /// Main content, most important first
+    #[serde(rename = "prioritizedContentFields", default)]
+    pub content_fields: Vec<SemanticField>,
+    /// Keywords or tags, most important first
+    #[serde(rename = "prioritizedKeywordsFields", default)]
+    pub keywords_fields: Vec<SemanticField>,
+}
+
+impl SemanticPrioritizedFields {
+    /// Set the title field
+    pub fn title_field(mut self, field_name: impl Into<String>) -> Self {
+        self.title_field = Some(SemanticField::new(field_name));
+        self
+    }
+
+    /// Add a content field
+    pub fn content_field(mut self, field_name: impl Into<String>) -> Self {
+        self.content_fields.push(SemanticField::new(field_name));
+        self
+    }
+
+    /// Add a keywords field
+    pub fn keywords_field(mut self, field_name: impl Into<String>) -> Self {
+        self.keywords_fields.push(SemanticField::new(field_name));
+        self
+    }
+}
+
+/// Reference to an index field
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct SemanticField {
+    /// Field name
+    pub field_name: String,
+}
+
+impl SemanticField {
+    /// Reference a field by name
+    pub fn new(field_name: impl Into<String>) -> Self {
+        Self {
+            field_name: field_name.into(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn prioritized_fields_round_trip() {
+        let semantic = SemanticSearch::default()
+            .default_configuration("default")
+            .configuration(SemanticConfiguration::new(
+                "default",
+                SemanticPrioritizedFields::default()
+                    .title_field("name")
+                    .content_field("description")
+                    .content_field("summary")
+                    .keywords_field("tags"),
+            ));
+        let expected = json!({
+            "defaultConfiguration": "default",
+            "configurations": [{
+                "name": "default",
+                "prioritizedFields": {
+                    "titleField": { "fieldName": "name" },
+                    "prioritizedContentFields": [
+                        { "fieldName": "description" },
+                        { "fieldName": "summary" }
+                    ],
+                    "prioritizedKeywordsFields": [{ "fieldName": "tags" }]
+                }
+            }]
+        });
+        assert_eq!(serde_json::to_value(&semantic).unwrap(), expected);
+        assert_eq!(
+            serde_json::from_value::<SemanticSearch>(expected).unwrap(),
+            semantic
+        );
+
+        // Field lists and the title are optional in responses
+        let minimal: SemanticConfiguration = serde_json::from_value(json!({
+            "name": "minimal",
+            "prioritizedFields": {},
+            "rankingOrder": "RerankerScore"
+        }))
+        .unwrap();
+        assert_eq!(
+            minimal.prioritized_fields,
+            SemanticPrioritizedFields::default()
+        );
+        assert_eq!(minimal.ranking_order.as_deref(), Some("RerankerScore"));
+    }
+}
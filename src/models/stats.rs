@@ -0,0 +1,200 @@
+use serde::{Deserialize, Serialize};
+
+/// Size of an index
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct IndexStatistics {
+    /// Number of documents
+    pub document_count: u64,
+    /// Storage used, in bytes
+    pub storage_size: u64,
+    /// Memory used by vector indexes, in bytes
+    #[serde(default)]
+    pub vector_index_size: u64,
+}
+
+/// Resource usage and limits of a search service
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq)]
+#[serde(default)]
+pub struct ServiceStatistics {
+    /// Usage and quota of each resource
+    pub counters: ServiceCounters,
+    /// Limits of the service tier
+    pub limits: ServiceLimits,
+}
+
+impl ServiceStatistics {
+    /// Utilization of each resource that has a quota, highest first
+    pub fn quota_usage(&self) -> Vec<QuotaUsage> {
+        let counters = &self.counters;
+        let mut usage: Vec<QuotaUsage> = [
+            ("documentCount", Some(&counters.document_count)),
+            ("indexesCount", Some(&counters.indexes_count)),
+            ("indexersCount", Some(&counters.indexers_count)),
+            ("dataSourcesCount", Some(&counters.data_sources_count)),
+            ("storageSize", Some(&counters.storage_size)),
+            ("synonymMaps", Some(&counters.synonym_maps)),
+            ("skillsetCount", counters.skillset_count.as_ref()),
+            ("vectorIndexSize", counters.vector_index_size.as_ref()),
+            ("aliasesCount", counters.aliases_count.as_ref()),
+        ]
+        .into_iter()
+        .filter_map(|(resource, counter)| {
+            let counter = counter?;
+            Some(QuotaUsage {
+                resource,
+                usage: counter.usage,
+                quota: counter.quota?,
+                utilization: counter.utilization()?,
+            })
+        })
+        .collect();
+        usage.sort_by(|a, b| b.utilization.total_cmp(&a.utilization));
+        usage
+    }
+
+    /// Resources whose utilization is at or above `threshold` (0 to 1)
+    pub fn near_quota(&self, threshold: f64) -> Vec<QuotaUsage> {
+        self.quota_usage()
+            .into_iter()
+            .filter(|usage| usage.utilization >= threshold)
+            .collect()
+    }
+}
+
+/// Usage counters of a search service
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq)]
+#[serde(default, rename_all = "camelCase")]
+pub struct ServiceCounters {
+    /// Documents across all indexes
+    pub document_count: ResourceCounter,
+    /// Indexes
+    pub indexes_count: ResourceCounter,
+    /// Indexers
+    pub indexers_count: ResourceCounter,
+    /// Data sources
+    pub data_sources_count: ResourceCounter,
+    /// Storage, in bytes
+    pub storage_size: ResourceCounter,
+    /// Synonym maps
+    pub synonym_maps: ResourceCounter,
+    /// Skillsets
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub skillset_count: Option<ResourceCounter>,
+    /// Vector index memory, in bytes
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub vector_index_size: Option<ResourceCounter>,
+    /// Index aliases
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub aliases_count: Option<ResourceCounter>,
+}
+
+/// Usage of a resource and its quota, if any
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ResourceCounter {
+    /// Current usage
+    pub usage: u64,
+    /// Maximum allowed usage; `None` when unlimited
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub quota: Option<u64>,
+}
+
+impl ResourceCounter {
+    /// Fraction of the quota in use; `None` when unlimited
+    pub fn utilization(&self) -> Option<f64> {
+        match self.quota {
+            Some(0) => Some(if self.usage > 0 { f64::INFINITY } else { 0.0 }),
+            Some(quota) => Some(self.usage as f64 / quota as f64),
+            None => None,
+        }
+    }
+}
+
+/// Limits of a search service tier
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct ServiceLimits {
+    /// Maximum number of fields per index
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_fields_per_index: Option<u64>,
+    /// Maximum nesting depth of complex fields
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_field_nesting_depth_per_index: Option<u64>,
+    /// Maximum number of complex collection fields per index
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_complex_collection_fields_per_index: Option<u64>,
+    /// Maximum number of objects in complex collections per document
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_complex_objects_in_collections_per_document: Option<u64>,
+    /// Maximum storage per index, in bytes
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_storage_per_index: Option<u64>,
+}
+
+/// How close a resource is to its quota
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct QuotaUsage {
+    /// Name of the counter (e.g. `storageSize`)
+    pub resource: &'static str,
+    /// Current usage
+    pub usage: u64,
+    /// Maximum allowed usage
+    pub quota: u64,
+    /// Fraction of the quota in use
+    pub utilization: f64,
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn quota_usage_skips_unlimited_counters_and_sorts_by_utilization() {
+        let stats: ServiceStatistics = serde_json::from_value(json!({
+            "counters": {
+                "documentCount": { "usage": 500, "quota": null },
+                "indexesCount": { "usage": 9, "quota": 10 },
+                "storageSize": { "usage": 1, "quota": 4 },
+                "vectorIndexSize": { "usage": 3, "quota": 0 }
+            },
+            "limits": { "maxFieldsPerIndex": 1000 }
+        }))
+        .unwrap();
+        assert_eq!(stats.limits.max_fields_per_index, Some(1000));
+
+        let resources: Vec<&str> = stats
+            .quota_usage()
+            .iter()
+            .map(|usage| usage.resource)
+            .collect();
+        // Missing counters default to zero usage of an unlimited quota
+        assert_eq!(
+            resources,
+            ["vectorIndexSize", "indexesCount", "storageSize"]
+        );
+
+        let near = stats.near_quota(0.9);
+        assert_eq!(near.len(), 2);
+        assert_eq!(near[1].resource, "indexesCount");
+        assert_eq!(near[1].utilization, 0.9);
+    }
+
+    #[test]
+    fn utilization_of_an_empty_quota() {
+        let counter = ResourceCounter {
+            usage: 0,
+            quota: Some(0),
+        };
+        assert_eq!(counter.utilization(), Some(0.0));
+        assert_eq!(ResourceCounter::default().utilization(), None);
+    }
+
+    #[test]
+    fn index_statistics_default_vector_size() {
+        let stats: IndexStatistics =
+            serde_json::from_value(json!({ "documentCount": 10, "storageSize": 2048 })).unwrap();
+        assert_eq!(stats.vector_index_size, 0);
+    }
+}
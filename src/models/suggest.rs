@@ -0,0 +1,111 @@
+use serde::{Deserialize, Serialize};
+
+use super::search::{deserialize_option_vec_from_string, serialize_option_vec_as_string};
+
+/// Suggest request parameters
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct SuggestRequest {
+    /// Partial search text (1 to 100 characters)
+    pub search: String,
+    /// Name of the suggester defined in the index
+    pub suggester_name: String,
+    /// Use fuzzy matching, tolerating one substitution or missing character
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fuzzy: Option<bool>,
+    /// Filter expression
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub filter: Option<String>,
+    /// Highlight pre/post tags
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub highlight_post_tag: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub highlight_pre_tag: Option<String>,
+    /// % of index that must be covered to declare query successful
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub minimum_coverage: Option<u8>,
+    /// Orderby_expression
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub orderby: Option<String>,
+    /// Search fields (must be part of the suggester)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(serialize_with = "serialize_option_vec_as_string")]
+    #[serde(default, deserialize_with = "deserialize_option_vec_from_string")]
+    pub search_fields: Option<Vec<String>>,
+    /// Select fields
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(serialize_with = "serialize_option_vec_as_string")]
+    #[serde(default, deserialize_with = "deserialize_option_vec_from_string")]
+    pub select: Option<Vec<String>>,
+    /// Number of suggestions to return (1 to 100, default 5)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub top: Option<u8>,
+}
+
+impl SuggestRequest {
+    /// Create a request for the given text and suggester
+    pub fn new(search: impl Into<String>, suggester_name: impl Into<String>) -> Self {
+        Self {
+            search: search.into(),
+            suggester_name: suggester_name.into(),
+            ..Default::default()
+        }
+    }
+}
+
+/// Suggest result
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct SuggestResult<T> {
+    /// Suggested text, with highlight tags if requested
+    #[serde(rename = "@search.text")]
+    pub text: String,
+    /// The selected document fields
+    #[serde(flatten)]
+    pub document: T,
+}
+
+/// Suggest response
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct SuggestResponse<T> {
+    /// Suggestions
+    pub value: Vec<SuggestResult<T>>,
+    /// % of the index covered by the query, if minimum coverage was requested
+    #[serde(rename = "@search.coverage", skip_serializing_if = "Option::is_none")]
+    pub coverage: Option<f64>,
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{json, Value};
+
+    use super::*;
+
+    #[test]
+    fn request_joins_field_lists() {
+        let request = SuggestRequest {
+            select: Some(vec!["id".to_string(), "name".to_string()]),
+            top: Some(3),
+            ..SuggestRequest::new("lux", "sg")
+        };
+        let value = serde_json::to_value(&request).unwrap();
+        assert_eq!(
+            value,
+            json!({ "search": "lux", "suggesterName": "sg", "select": "id,name", "top": 3 })
+        );
+        let parsed: SuggestRequest = serde_json::from_value(value).unwrap();
+        assert_eq!(parsed.select.unwrap(), ["id", "name"]);
+        assert!(parsed.search_fields.is_none());
+    }
+
+    #[test]
+    fn response_flattens_selected_fields() {
+        let response: SuggestResponse<Value> = serde_json::from_value(json!({
+            "value": [{ "@search.text": "<em>Lux</em>ury", "id": "1" }],
+            "@search.coverage": 100.0
+        }))
+        .unwrap();
+        assert_eq!(response.value[0].text, "<em>Lux</em>ury");
+        assert_eq!(response.value[0].document, json!({ "id": "1" }));
+        assert_eq!(response.coverage, Some(100.0));
+    }
+}
@@ -0,0 +1,278 @@
+use serde::{Deserialize, Serialize};
+
+use super::search::{deserialize_vec_from_string, serialize_vec_as_string, VectorKind};
+
+/// Vector query executed alongside (or instead of) the text search
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct VectorQuery {
+    /// What is being searched for
+    #[serde(flatten)]
+    pub input: VectorQueryInput,
+    /// Vector fields to search
+    #[serde(serialize_with = "serialize_vec_as_string")]
+    #[serde(deserialize_with = "deserialize_vec_from_string")]
+    pub fields: Vec<String>,
+    /// Number of nearest neighbours to return
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub k: Option<u32>,
+    /// Search every vector instead of the approximate index
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub exhaustive: Option<bool>,
+    /// Oversampling factor for compressed vector fields
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub oversampling: Option<f64>,
+    /// Relative weight of this query in hybrid ranking
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub weight: Option<f32>,
+    /// Minimum similarity or score of returned matches
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub threshold: Option<VectorThreshold>,
+    /// Filter applied to this query instead of the request filter
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub filter_override: Option<String>,
+}
+
+impl VectorQuery {
+    fn new(input: VectorQueryInput, fields: Vec<String>) -> Self {
+        Self {
+            input,
+            fields,
+            k: None,
+            exhaustive: None,
+            oversampling: None,
+            weight: None,
+            threshold: None,
+            filter_override: None,
+        }
+    }
+
+    /// Query with a precomputed embedding
+    pub fn vector<I, S>(vector: Vec<f32>, fields: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        Self::new(
+            VectorQueryInput::Vector { vector },
+            fields.into_iter().map(Into::into).collect(),
+        )
+    }
+
+    /// Query with text vectorized by the field's vectorizer
+    pub fn text<I, S>(text: impl Into<String>, fields: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        Self::new(
+            VectorQueryInput::Text { text: text.into() },
+            fields.into_iter().map(Into::into).collect(),
+        )
+    }
+
+    /// Query with an image URL vectorized by a multimodal vectorizer
+    pub fn image_url<I, S>(url: impl Into<String>, fields: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        Self::new(
+            VectorQueryInput::ImageUrl { url: url.into() },
+            fields.into_iter().map(Into::into).collect(),
+        )
+    }
+
+    /// Query with a base64 encoded image vectorized by a multimodal vectorizer
+    pub fn image_binary<I, S>(base64_image: impl Into<String>, fields: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        Self::new(
+            VectorQueryInput::ImageBinary {
+                base64_image: base64_image.into(),
+            },
+            fields.into_iter().map(Into::into).collect(),
+        )
+    }
+
+    /// Set the number of nearest neighbours to return
+    pub fn k(mut self, k: u32) -> Self {
+        self.k = Some(k);
+        self
+    }
+
+    /// Search every vector instead of the approximate index
+    pub fn exhaustive(mut self, exhaustive: bool) -> Self {
+        self.exhaustive = Some(exhaustive);
+        self
+    }
+
+    /// Set the oversampling factor for compressed vector fields
+    pub fn oversampling(mut self, oversampling: f64) -> Self {
+        self.oversampling = Some(oversampling);
+        self
+    }
+
+    /// Set the relative weight in hybrid ranking
+    pub fn weight(mut self, weight: f32) -> Self {
+        self.weight = Some(weight);
+        self
+    }
+
+    /// Only return matches above the threshold
+    pub fn threshold(mut self, threshold: VectorThreshold) -> Self {
+        self.threshold = Some(threshold);
+        self
+    }
+
+    /// Apply this filter instead of the request filter
+    pub fn filter_override(mut self, filter: impl Into<String>) -> Self {
+        self.filter_override = Some(filter.into());
+        self
+    }
+}
+
+impl From<VectorKind> for VectorQuery {
+    fn from(kind: VectorKind) -> Self {
+        let (query, k, weight) = match kind {
+            VectorKind::VectorQuery {
+                vector,
+                k,
+                fields,
+                weight,
+                ..
+            } => (VectorQuery::vector(vector, fields), k, weight),
+            VectorKind::TextQuery {
+                text,
+                k,
+                fields,
+                weight,
+                ..
+            } => (VectorQuery::text(text, fields), k, weight),
+        };
+        VectorQuery {
+            k: Some(k.into()),
+            weight,
+            ..query
+        }
+    }
+}
+
+/// Input of a vector query, serialized with its `kind`
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(tag = "kind", rename_all = "camelCase")]
+pub enum VectorQueryInput {
+    /// vector
+    Vector { vector: Vec<f32> },
+    /// text
+    Text { text: String },
+    /// imageUrl
+    ImageUrl { url: String },
+    /// imageBinary
+    #[serde(rename_all = "camelCase")]
+    ImageBinary { base64_image: String },
+}
+
+/// Threshold a vector match must meet to be returned
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq)]
+#[serde(tag = "kind", rename_all = "camelCase")]
+pub enum VectorThreshold {
+    /// Minimum similarity in the field's metric
+    VectorSimilarity { value: f64 },
+    /// Minimum `@search.score`
+    SearchScore { value: f64 },
+}
+
+/// When the request filter is applied relative to the vector search
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum VectorFilterMode {
+    /// Filter before searching
+    PreFilter,
+    /// Filter the nearest neighbours after searching
+    PostFilter,
+    /// Filter after searching, without widening the search
+    StrictPostFilter,
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn input_is_flattened_with_its_kind() {
+        let cases = [
+            (
+                VectorQuery::vector(vec![0.25, -1.0], ["a", "b"]).k(5),
+                json!({ "kind": "vector", "vector": [0.25, -1.0], "fields": "a,b", "k": 5 }),
+            ),
+            (
+                VectorQuery::text("sea", ["a"]).exhaustive(true),
+                json!({ "kind": "text", "text": "sea", "fields": "a", "exhaustive": true }),
+            ),
+            (
+                VectorQuery::image_url("https://example.com/a.png", ["img"]).weight(0.5),
+                json!({
+                    "kind": "imageUrl",
+                    "url": "https://example.com/a.png",
+                    "fields": "img",
+                    "weight": 0.5
+                }),
+            ),
+            (
+                VectorQuery::image_binary("aGk=", ["img"])
+                    .oversampling(2.0)
+                    .threshold(VectorThreshold::VectorSimilarity { value: 0.8 })
+                    .filter_override("rating gt 3"),
+                json!({
+                    "kind": "imageBinary",
+                    "base64Image": "aGk=",
+                    "fields": "img",
+                    "oversampling": 2.0,
+                    "threshold": { "kind": "vectorSimilarity", "value": 0.8 },
+                    "filterOverride": "rating gt 3"
+                }),
+            ),
+        ];
+        for (query, expected) in cases {
+            assert_eq!(serde_json::to_value(&query).unwrap(), expected);
+            let parsed: VectorQuery = serde_json::from_value(expected).unwrap();
+            assert_eq!(parsed, query);
+        }
+
+        let unknown = json!({ "kind": "sparse", "fields": "a" });
+        assert!(serde_json::from_value::<VectorQuery>(unknown).is_err());
+        let threshold = json!({ "kind": "searchScore", "value": 1.5 });
+        assert_eq!(
+            serde_json::from_value::<VectorThreshold>(threshold).unwrap(),
+            VectorThreshold::SearchScore { value: 1.5 }
+        );
+    }
+
+    #[test]
+    fn vector_kind_converts_into_vector_query() {
+        let kind = VectorKind::VectorQuery {
+            kind: "vector".to_string(),
+            vector: vec![1.0],
+            k: 7,
+            fields: vec!["v".to_string()],
+            weight: Some(3.0),
+        };
+        assert_eq!(
+            VectorQuery::from(kind),
+            VectorQuery::vector(vec![1.0], ["v"]).k(7).weight(3.0)
+        );
+
+        let kind: VectorKind =
+            serde_json::from_value(json!({ "kind": "text", "text": "t", "k": 2, "fields": "a,b" }))
+                .unwrap();
+        assert_eq!(
+            VectorQuery::from(kind),
+            VectorQuery::text("t", ["a", "b"]).k(2)
+        );
+    }
+}
@@ -0,0 +1,734 @@
+use std::collections::HashMap;
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::error::ValidationError;
+
+/// Vector search configuration of an index
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct VectorSearch {
+    /// Algorithms used to index and query vectors
+    #[serde(default)]
+    pub algorithms: Vec<VectorSearchAlgorithm>,
+    /// Profiles referenced by vector fields
+    #[serde(default)]
+    pub profiles: Vec<VectorSearchProfile>,
+    /// Vectorizers turning text or images into query vectors
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub vectorizers: Option<Vec<VectorSearchVectorizer>>,
+    /// Compressions applied to vector indexes
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub compressions: Option<Vec<VectorSearchCompression>>,
+}
+
+impl VectorSearch {
+    /// Add an algorithm
+    pub fn algorithm(mut self, algorithm: impl Into<VectorSearchAlgorithm>) -> Self {
+        self.algorithms.push(algorithm.into());
+        self
+    }
+
+    /// Add a profile
+    pub fn profile(mut self, profile: VectorSearchProfile) -> Self {
+        self.profiles.push(profile);
+        self
+    }
+
+    /// Add a vectorizer
+    pub fn vectorizer(mut self, vectorizer: impl Into<VectorSearchVectorizer>) -> Self {
+        self.vectorizers
+            .get_or_insert_with(Vec::new)
+            .push(vectorizer.into());
+        self
+    }
+
+    /// Add a compression
+    pub fn compression(mut self, compression: impl Into<VectorSearchCompression>) -> Self {
+        self.compressions
+            .get_or_insert_with(Vec::new)
+            .push(compression.into());
+        self
+    }
+
+    /// Profile with the given name
+    pub fn find_profile(&self, name: &str) -> Option<&VectorSearchProfile> {
+        self.profiles.iter().find(|profile| profile.name == name)
+    }
+
+    /// Check that every profile references a defined algorithm, vectorizer and compression
+    pub fn validate(&self) -> Result<(), ValidationError> {
+        for profile in &self.profiles {
+            let missing = |reference: &str| ValidationError::UnknownVectorSearchReference {
+                profile: profile.name.clone(),
+                reference: reference.to_string(),
+            };
+            if !self
+                .algorithms
+                .iter()
+                .any(|algorithm| algorithm.name() == Some(&profile.algorithm))
+            {
+                return Err(missing(&profile.algorithm));
+            }
+            if let Some(vectorizer) = &profile.vectorizer {
+                if !self
+                    .vectorizers
+                    .iter()
+                    .flatten()
+                    .any(|defined| defined.name() == Some(vectorizer))
+                {
+                    return Err(missing(vectorizer));
+                }
+            }
+            if let Some(compression) = &profile.compression {
+                if !self
+                    .compressions
+                    .iter()
+                    .flatten()
+                    .any(|defined| defined.name() == Some(compression))
+                {
+                    return Err(missing(compression));
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Combination of algorithm, vectorizer and compression used by vector fields
+///
+/// References can also be given as the definitions themselves, as in
+/// `VectorSearchProfile::new("default", &hnsw).compression(&int8)`, so that
+/// names can't drift apart; [`crate::operations::index::IndexTrait`] checks
+/// them before creating or updating an index.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct VectorSearchProfile {
+    /// Profile name
+    pub name: String,
+    /// Name of the algorithm
+    pub algorithm: String,
+    /// Name of the vectorizer used for text and image queries
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub vectorizer: Option<String>,
+    /// Name of the compression
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub compression: Option<String>,
+}
+
+impl VectorSearchProfile {
+    /// Create a profile using an algorithm
+    pub fn new(name: impl Into<String>, algorithm: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            algorithm: algorithm.into(),
+            vectorizer: None,
+            compression: None,
+        }
+    }
+
+    /// Set the vectorizer
+    pub fn vectorizer(mut self, vectorizer: impl Into<String>) -> Self {
+        self.vectorizer = Some(vectorizer.into());
+        self
+    }
+
+    /// Set the compression
+    pub fn compression(mut self, compression: impl Into<String>) -> Self {
+        self.compression = Some(compression.into());
+        self
+    }
+}
+
+impl From<&VectorSearchProfile> for String {
+    fn from(profile: &VectorSearchProfile) -> Self {
+        profile.name.clone()
+    }
+}
+
+/// Algorithm used to index and query vectors, serialized with its `kind`
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(tag = "kind")]
+pub enum VectorSearchAlgorithm {
+    /// hnsw
+    #[serde(rename = "hnsw")]
+    Hnsw(HnswAlgorithm),
+    /// exhaustiveKnn
+    #[serde(rename = "exhaustiveKnn")]
+    ExhaustiveKnn(ExhaustiveKnnAlgorithm),
+    /// Unrecognized kind, kept as received
+    #[serde(untagged)]
+    Unknown(Value),
+}
+
+impl VectorSearchAlgorithm {
+    /// Name referenced by profiles
+    pub fn name(&self) -> Option<&str> {
+        match self {
+            VectorSearchAlgorithm::Hnsw(inner) => Some(&inner.name),
+            VectorSearchAlgorithm::ExhaustiveKnn(inner) => Some(&inner.name),
+            VectorSearchAlgorithm::Unknown(value) => value.get("name").and_then(Value::as_str),
+        }
+    }
+}
+
+impl From<HnswAlgorithm> for VectorSearchAlgorithm {
+    fn from(inner: HnswAlgorithm) -> Self {
+        VectorSearchAlgorithm::Hnsw(inner)
+    }
+}
+
+impl From<&HnswAlgorithm> for String {
+    fn from(definition: &HnswAlgorithm) -> Self {
+        definition.name.clone()
+    }
+}
+
+impl From<ExhaustiveKnnAlgorithm> for VectorSearchAlgorithm {
+    fn from(inner: ExhaustiveKnnAlgorithm) -> Self {
+        VectorSearchAlgorithm::ExhaustiveKnn(inner)
+    }
+}
+
+impl From<&ExhaustiveKnnAlgorithm> for String {
+    fn from(definition: &ExhaustiveKnnAlgorithm) -> Self {
+        definition.name.clone()
+    }
+}
+
+/// Approximate nearest neighbour search with a navigable small world graph
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq)]
+pub struct HnswAlgorithm {
+    /// Algorithm name
+    pub name: String,
+    /// Graph parameters
+    #[serde(rename = "hnswParameters", skip_serializing_if = "Option::is_none")]
+    pub parameters: Option<HnswParameters>,
+}
+
+impl HnswAlgorithm {
+    /// Create an algorithm with the given parameters
+    pub fn new(name: impl Into<String>, parameters: HnswParameters) -> Self {
+        Self {
+            name: name.into(),
+            parameters: Some(parameters),
+        }
+    }
+}
+
+/// Parameters of the HNSW graph
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct HnswParameters {
+    /// Number of bi-directional links per node (4 to 10, default 4)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub m: Option<u32>,
+    /// Size of the candidate list while indexing (100 to 1000, default 400)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ef_construction: Option<u32>,
+    /// Size of the candidate list while searching (100 to 1000, default 500)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ef_search: Option<u32>,
+    /// Similarity metric
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metric: Option<VectorSearchAlgorithmMetric>,
+}
+
+impl HnswParameters {
+    /// Set the number of links per node
+    pub fn m(mut self, m: u32) -> Self {
+        self.m = Some(m);
+        self
+    }
+
+    /// Set the candidate list size while indexing
+    pub fn ef_construction(mut self, ef_construction: u32) -> Self {
+        self.ef_construction = Some(ef_construction);
+        self
+    }
+
+    /// Set the candidate list size while searching
+    pub fn ef_search(mut self, ef_search: u32) -> Self {
+        self.ef_search = Some(ef_search);
+        self
+    }
+
+    /// Set the similarity metric
+    pub fn metric(mut self, metric: VectorSearchAlgorithmMetric) -> Self {
+        self.metric = Some(metric);
+        self
+    }
+}
+
+/// Brute-force search over every vector
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq)]
+pub struct ExhaustiveKnnAlgorithm {
+    /// Algorithm name
+    pub name: String,
+    /// Search parameters
+    #[serde(
+        rename = "exhaustiveKnnParameters",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub parameters: Option<ExhaustiveKnnParameters>,
+}
+
+impl ExhaustiveKnnAlgorithm {
+    /// Create an algorithm using the given metric
+    pub fn new(name: impl Into<String>, metric: VectorSearchAlgorithmMetric) -> Self {
+        Self {
+            name: name.into(),
+            parameters: Some(ExhaustiveKnnParameters {
+                metric: Some(metric),
+            }),
+        }
+    }
+}
+
+/// Parameters of exhaustive KNN
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ExhaustiveKnnParameters {
+    /// Similarity metric
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metric: Option<VectorSearchAlgorithmMetric>,
+}
+
+/// Similarity metric between vectors
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum VectorSearchAlgorithmMetric {
+    /// cosine
+    Cosine,
+    /// euclidean
+    Euclidean,
+    /// dotProduct
+    DotProduct,
+    /// hamming (packed binary vectors only)
+    Hamming,
+}
+
+/// Vectorizer used at query time, serialized with its `kind`
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(tag = "kind")]
+pub enum VectorSearchVectorizer {
+    /// azureOpenAI
+    #[serde(rename = "azureOpenAI")]
+    AzureOpenAI(AzureOpenAIVectorizer),
+    /// customWebApi
+    #[serde(rename = "customWebApi")]
+    CustomWebApi(CustomWebApiVectorizer),
+    /// Unrecognized kind, kept as received
+    #[serde(untagged)]
+    Unknown(Value),
+}
+
+impl VectorSearchVectorizer {
+    /// Name referenced by profiles
+    pub fn name(&self) -> Option<&str> {
+        match self {
+            VectorSearchVectorizer::AzureOpenAI(inner) => Some(&inner.name),
+            VectorSearchVectorizer::CustomWebApi(inner) => Some(&inner.name),
+            VectorSearchVectorizer::Unknown(value) => value.get("name").and_then(Value::as_str),
+        }
+    }
+}
+
+impl From<AzureOpenAIVectorizer> for VectorSearchVectorizer {
+    fn from(inner: AzureOpenAIVectorizer) -> Self {
+        VectorSearchVectorizer::AzureOpenAI(inner)
+    }
+}
+
+impl From<&AzureOpenAIVectorizer> for String {
+    fn from(definition: &AzureOpenAIVectorizer) -> Self {
+        definition.name.clone()
+    }
+}
+
+impl From<CustomWebApiVectorizer> for VectorSearchVectorizer {
+    fn from(inner: CustomWebApiVectorizer) -> Self {
+        VectorSearchVectorizer::CustomWebApi(inner)
+    }
+}
+
+impl From<&CustomWebApiVectorizer> for String {
+    fn from(definition: &CustomWebApiVectorizer) -> Self {
+        definition.name.clone()
+    }
+}
+
+/// Vectorizer calling an Azure OpenAI embedding deployment
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
+pub struct AzureOpenAIVectorizer {
+    /// Vectorizer name
+    pub name: String,
+    /// Deployment to call
+    #[serde(
+        rename = "azureOpenAIParameters",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub parameters: Option<AzureOpenAIParameters>,
+}
+
+/// Azure OpenAI deployment used by a vectorizer
+#[derive(Serialize, Deserialize, Clone, Default, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct AzureOpenAIParameters {
+    /// Resource URI (e.g. `https://my-resource.openai.azure.com`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub resource_uri: Option<String>,
+    /// Deployment name
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub deployment_id: Option<String>,
+    /// API key; omit to use the service's managed identity
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub api_key: Option<String>,
+    /// Embedding model of the deployment (e.g. `text-embedding-3-small`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub model_name: Option<String>,
+    /// User-assigned managed identity used for authentication
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub auth_identity: Option<Value>,
+}
+
+impl fmt::Debug for AzureOpenAIParameters {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("AzureOpenAIParameters")
+            .field("resource_uri", &self.resource_uri)
+            .field("deployment_id", &self.deployment_id)
+            .field("api_key", &self.api_key.as_ref().map(|_| "<redacted>"))
+            .field("model_name", &self.model_name)
+            .field("auth_identity", &self.auth_identity)
+            .finish()
+    }
+}
+
+/// Vectorizer calling a custom web API
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
+pub struct CustomWebApiVectorizer {
+    /// Vectorizer name
+    pub name: String,
+    /// Endpoint to call
+    #[serde(
+        rename = "customWebApiParameters",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub parameters: Option<CustomWebApiParameters>,
+}
+
+/// Web API endpoint used by a vectorizer
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct CustomWebApiParameters {
+    /// Endpoint URI
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub uri: Option<String>,
+    /// Headers sent with each request
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub http_headers: Option<HashMap<String, String>>,
+    /// HTTP method (`POST` or `PUT`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub http_method: Option<String>,
+    /// ISO 8601 request timeout (e.g. `PT30S`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timeout: Option<String>,
+    /// Application id or URI for managed identity authentication
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub auth_resource_id: Option<String>,
+    /// User-assigned managed identity used for authentication
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub auth_identity: Option<Value>,
+}
+
+/// Compression of a vector index, serialized with its `kind`
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(tag = "kind")]
+pub enum VectorSearchCompression {
+    /// scalarQuantization
+    #[serde(rename = "scalarQuantization")]
+    ScalarQuantization(ScalarQuantizationCompression),
+    /// binaryQuantization
+    #[serde(rename = "binaryQuantization")]
+    BinaryQuantization(BinaryQuantizationCompression),
+    /// Unrecognized kind, kept as received
+    #[serde(untagged)]
+    Unknown(Value),
+}
+
+impl VectorSearchCompression {
+    /// Name referenced by profiles
+    pub fn name(&self) -> Option<&str> {
+        match self {
+            VectorSearchCompression::ScalarQuantization(inner) => Some(&inner.name),
+            VectorSearchCompression::BinaryQuantization(inner) => Some(&inner.name),
+            VectorSearchCompression::Unknown(value) => value.get("name").and_then(Value::as_str),
+        }
+    }
+}
+
+impl From<ScalarQuantizationCompression> for VectorSearchCompression {
+    fn from(inner: ScalarQuantizationCompression) -> Self {
+        VectorSearchCompression::ScalarQuantization(inner)
+    }
+}
+
+impl From<&ScalarQuantizationCompression> for String {
+    fn from(definition: &ScalarQuantizationCompression) -> Self {
+        definition.name.clone()
+    }
+}
+
+impl From<BinaryQuantizationCompression> for VectorSearchCompression {
+    fn from(inner: BinaryQuantizationCompression) -> Self {
+        VectorSearchCompression::BinaryQuantization(inner)
+    }
+}
+
+impl From<&BinaryQuantizationCompression> for String {
+    fn from(definition: &BinaryQuantizationCompression) -> Self {
+        definition.name.clone()
+    }
+}
+
+/// Quantizes each vector component to a narrower type
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ScalarQuantizationCompression {
+    /// Compression name
+    pub name: String,
+    /// Quantization parameters
+    #[serde(
+        rename = "scalarQuantizationParameters",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub parameters: Option<ScalarQuantizationParameters>,
+    /// Rescore results with the full precision vectors (older API versions)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rerank_with_original_vectors: Option<bool>,
+    /// Default oversampling factor (older API versions)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_oversampling: Option<f64>,
+    /// Rescoring of compressed results
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rescoring_options: Option<RescoringOptions>,
+    /// Number of leading dimensions kept (Matryoshka embeddings)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub truncation_dimension: Option<u32>,
+}
+
+/// Parameters of scalar quantization
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct ScalarQuantizationParameters {
+    /// Type of the quantized components (`int8`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub quantized_data_type: Option<String>,
+}
+
+/// Quantizes each vector component to a single bit
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct BinaryQuantizationCompression {
+    /// Compression name
+    pub name: String,
+    /// Rescore results with the full precision vectors (older API versions)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rerank_with_original_vectors: Option<bool>,
+    /// Default oversampling factor (older API versions)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_oversampling: Option<f64>,
+    /// Rescoring of compressed results
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rescoring_options: Option<RescoringOptions>,
+    /// Number of leading dimensions kept (Matryoshka embeddings)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub truncation_dimension: Option<u32>,
+}
+
+/// How compressed results are rescored
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct RescoringOptions {
+    /// Rescore the oversampled results with the original vectors
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enable_rescoring: Option<bool>,
+    /// Default oversampling factor
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_oversampling: Option<f64>,
+    /// Whether the original vectors are kept for rescoring
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rescore_storage_method: Option<RescoreStorageMethod>,
+}
+
+/// Storage of the original vectors of a compressed field
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum RescoreStorageMethod {
+    /// Keep the original vectors for rescoring
+    PreserveOriginals,
+    /// Discard the original vectors to save storage
+    DiscardOriginals,
+}
+
+#[cfg(test)]
+mod tests {
+    use serde::de::DeserializeOwned;
+    use serde_json::json;
+
+    use super::*;
+
+    fn round_trip<T: Serialize + DeserializeOwned>(value: Value) -> T {
+        let parsed: T = serde_json::from_value(value.clone()).unwrap();
+        assert_eq!(serde_json::to_value(&parsed).unwrap(), value);
+        parsed
+    }
+
+    #[test]
+    fn compressions_round_trip() {
+        let compression: VectorSearchCompression = round_trip(json!({
+            "kind": "scalarQuantization",
+            "name": "int8",
+            "scalarQuantizationParameters": { "quantizedDataType": "int8" },
+            "rescoringOptions": {
+                "enableRescoring": true,
+                "defaultOversampling": 4.0,
+                "rescoreStorageMethod": "preserveOriginals"
+            },
+            "truncationDimension": 512
+        }));
+        match &compression {
+            VectorSearchCompression::ScalarQuantization(scalar) => {
+                let rescoring = scalar.rescoring_options.unwrap();
+                assert_eq!(rescoring.enable_rescoring, Some(true));
+                assert_eq!(
+                    rescoring.rescore_storage_method,
+                    Some(RescoreStorageMethod::PreserveOriginals)
+                );
+                assert_eq!(scalar.truncation_dimension, Some(512));
+            }
+            other => panic!("{:?}", other),
+        }
+
+        let compression: VectorSearchCompression = round_trip(json!({
+            "kind": "binaryQuantization",
+            "name": "bits",
+            "rerankWithOriginalVectors": false,
+            "defaultOversampling": 10.0,
+            "rescoringOptions": { "rescoreStorageMethod": "discardOriginals" },
+            "truncationDimension": 256
+        }));
+        match &compression {
+            VectorSearchCompression::BinaryQuantization(binary) => {
+                assert_eq!(binary.rerank_with_original_vectors, Some(false));
+                assert_eq!(binary.truncation_dimension, Some(256));
+            }
+            other => panic!("{:?}", other),
+        }
+
+        let compression: VectorSearchCompression = round_trip(json!({
+            "kind": "productQuantization",
+            "name": "pq",
+            "subspaces": 8
+        }));
+        assert!(matches!(compression, VectorSearchCompression::Unknown(_)));
+        assert_eq!(compression.name(), Some("pq"));
+    }
+
+    #[test]
+    fn algorithms_and_vectorizers_round_trip() {
+        let vector_search: VectorSearch = round_trip(json!({
+            "algorithms": [
+                {
+                    "kind": "hnsw",
+                    "name": "hnsw",
+                    "hnswParameters": { "m": 4, "efConstruction": 400, "metric": "cosine" }
+                },
+                {
+                    "kind": "exhaustiveKnn",
+                    "name": "knn",
+                    "exhaustiveKnnParameters": { "metric": "hamming" }
+                },
+                { "kind": "diskAnn", "name": "disk" }
+            ],
+            "profiles": [
+                { "name": "default", "algorithm": "hnsw", "vectorizer": "openai" }
+            ],
+            "vectorizers": [
+                {
+                    "kind": "azureOpenAI",
+                    "name": "openai",
+                    "azureOpenAIParameters": {
+                        "resourceUri": "https://example.openai.azure.com",
+                        "deploymentId": "embedding",
+                        "apiKey": "secret",
+                        "modelName": "text-embedding-3-small"
+                    }
+                },
+                {
+                    "kind": "customWebApi",
+                    "name": "web",
+                    "customWebApiParameters": { "uri": "https://example.com", "timeout": "PT30S" }
+                },
+                { "kind": "aiServicesVision", "name": "vision" }
+            ]
+        }));
+        assert!(matches!(
+            vector_search.algorithms[0],
+            VectorSearchAlgorithm::Hnsw(_)
+        ));
+        assert!(matches!(
+            vector_search.algorithms[1],
+            VectorSearchAlgorithm::ExhaustiveKnn(_)
+        ));
+        match &vector_search.algorithms[2] {
+            VectorSearchAlgorithm::Unknown(value) => assert_eq!(value["kind"], "diskAnn"),
+            other => panic!("{:?}", other),
+        }
+        let vectorizers = vector_search.vectorizers.as_ref().unwrap();
+        assert!(matches!(vectorizers[2], VectorSearchVectorizer::Unknown(_)));
+        assert_eq!(vectorizers[2].name(), Some("vision"));
+        assert_eq!(vector_search.validate(), Ok(()));
+
+        // The API key is kept out of debug output
+        let debug = format!("{:?}", vectorizers[0]);
+        assert!(!debug.contains("secret"), "{}", debug);
+    }
+
+    #[test]
+    fn profiles_reference_definitions() {
+        let hnsw = HnswAlgorithm::new("hnsw", HnswParameters::default().m(8));
+        let int8 = ScalarQuantizationCompression {
+            name: "int8".to_string(),
+            ..Default::default()
+        };
+        let openai = AzureOpenAIVectorizer {
+            name: "openai".to_string(),
+            parameters: None,
+        };
+        let profile = VectorSearchProfile::new("default", &hnsw)
+            .compression(&int8)
+            .vectorizer(&openai);
+        assert_eq!(profile.algorithm, "hnsw");
+        assert_eq!(String::from(&profile), "default");
+
+        let vector_search = VectorSearch::default()
+            .algorithm(hnsw)
+            .compression(int8)
+            .vectorizer(openai)
+            .profile(profile);
+        assert_eq!(vector_search.validate(), Ok(()));
+
+        let dangling = vector_search
+            .clone()
+            .profile(VectorSearchProfile::new("other", "hnsw").compression("missing"));
+        assert_eq!(
+            dangling.validate(),
+            Err(ValidationError::UnknownVectorSearchReference {
+                profile: "other".to_string(),
+                reference: "missing".to_string()
+            })
+        );
+    }
+}
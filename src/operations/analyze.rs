@@ -0,0 +1,27 @@
+use crate::{
+    client::AzureSearchClient,
+    error::Result,
+    models::analyze::{AnalyzeRequest, AnalyzeResult},
+};
+
+pub trait AnalyzeTrait {
+    /// Break text into tokens the way an index's analyzer or tokenizer would
+    fn analyze(
+        &self,
+        index_name: &str,
+        request: &AnalyzeRequest,
+    ) -> impl std::future::Future<Output = Result<AnalyzeResult>> + Send;
+}
+
+impl AnalyzeTrait for AzureSearchClient {
+    /// Break text into tokens the way an index's analyzer or tokenizer would
+    async fn analyze(&self, index_name: &str, request: &AnalyzeRequest) -> Result<AnalyzeResult> {
+        let path = format!("indexes/{}/analyze", index_name);
+        self.send_request::<AnalyzeResult, AnalyzeRequest>(
+            reqwest::Method::POST,
+            &path,
+            Some(request),
+        )
+        .await
+    }
+}
@@ -0,0 +1,31 @@
+use crate::{
+    client::AzureSearchClient,
+    error::Result,
+    models::autocomplete::{AutocompleteRequest, AutocompleteResponse},
+};
+
+pub trait AutocompleteTrait {
+    /// Complete partial query terms
+    fn autocomplete(
+        &self,
+        index_name: &str,
+        request: &AutocompleteRequest,
+    ) -> impl std::future::Future<Output = Result<AutocompleteResponse>> + Send;
+}
+
+impl AutocompleteTrait for AzureSearchClient {
+    /// Complete partial query terms
+    async fn autocomplete(
+        &self,
+        index_name: &str,
+        request: &AutocompleteRequest,
+    ) -> Result<AutocompleteResponse> {
+        let path = format!("indexes/{}/docs/autocomplete", index_name);
+        self.send_request::<AutocompleteResponse, AutocompleteRequest>(
+            reqwest::Method::POST,
+            &path,
+            Some(request),
+        )
+        .await
+    }
+}
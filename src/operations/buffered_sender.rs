@@ -0,0 +1,805 @@
+//! Long-lived, buffered sender for high-throughput indexing
+//!
+//! Actions are queued through an async channel and sent in batches by a
+//! background task, which flushes when a batch is full or the flush interval
+//! elapses and retries transiently failed documents with exponential backoff.
+
+use std::collections::{HashMap, VecDeque};
+use std::marker::PhantomData;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use std::time::Duration;
+
+use futures::Stream;
+use serde::Serialize;
+use serde_json::Value;
+use tokio::sync::{mpsc, oneshot};
+use tokio::task::{Id, JoinError, JoinHandle, JoinSet};
+use tokio::time::Instant;
+
+use crate::{
+    client::AzureSearchClient,
+    error::{Error, Result},
+    models::document::{IndexAction, IndexBatch, IndexingResult},
+    operations::document::DocumentTrait,
+    retry::RetryPolicy,
+};
+
+/// Options for [`BufferedSender`]
+#[derive(Debug, Clone)]
+pub struct BufferedSenderOptions {
+    /// Number of queued actions that triggers a flush
+    pub batch_size: usize,
+    /// Maximum time an action waits in the buffer before being sent
+    pub flush_interval: Duration,
+    /// Maximum number of batches sent concurrently
+    pub max_in_flight: usize,
+    /// Capacity of the action channel; senders wait when it is full
+    pub channel_capacity: usize,
+    /// Maximum number of attempts per action before it is reported as permanently failed
+    pub max_attempts: u32,
+    /// Backoff applied between attempts of the same action
+    ///
+    /// The client's own retry policy is disabled for requests sent by the
+    /// sender, so this is the only retry layer.
+    pub retry_policy: RetryPolicy,
+}
+
+impl Default for BufferedSenderOptions {
+    fn default() -> Self {
+        Self {
+            batch_size: 512,
+            flush_interval: Duration::from_secs(60),
+            max_in_flight: 4,
+            channel_capacity: 10_000,
+            max_attempts: 3,
+            retry_policy: RetryPolicy::default(),
+        }
+    }
+}
+
+/// Outcome of a single action sent by a [`BufferedSender`]
+#[derive(Debug, Clone)]
+pub enum IndexingEvent {
+    /// The action succeeded
+    Succeeded {
+        action: IndexAction<Value>,
+        result: IndexingResult,
+    },
+    /// The action failed and will be retried
+    Failed {
+        action: IndexAction<Value>,
+        result: IndexingResult,
+        attempts: u32,
+    },
+    /// The action failed and will not be retried
+    PermanentlyFailed {
+        action: IndexAction<Value>,
+        result: IndexingResult,
+        attempts: u32,
+    },
+}
+
+/// Stream of [`IndexingEvent`]s produced by a [`BufferedSender`]
+///
+/// The stream ends once the sender has been closed and every pending action
+/// has been reported.
+#[derive(Debug)]
+pub struct IndexingEvents {
+    receiver: mpsc::UnboundedReceiver<IndexingEvent>,
+}
+
+impl IndexingEvents {
+    /// Receive the next event
+    pub async fn recv(&mut self) -> Option<IndexingEvent> {
+        self.receiver.recv().await
+    }
+}
+
+impl Stream for IndexingEvents {
+    type Item = IndexingEvent;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.receiver.poll_recv(cx)
+    }
+}
+
+enum Command {
+    Action(PendingAction),
+    Flush(oneshot::Sender<()>),
+}
+
+/// Buffered, background sender for index actions
+///
+/// Documents must contain the index key field, which is used to match
+/// per-document results to the queued actions.
+pub struct BufferedSender<T> {
+    commands: mpsc::Sender<Command>,
+    task: JoinHandle<()>,
+    key_field: String,
+    _document: PhantomData<fn(T)>,
+}
+
+impl<T: Serialize> BufferedSender<T> {
+    /// Start a sender for the given index and return it with its event stream
+    pub fn new(
+        client: AzureSearchClient,
+        index_name: impl Into<String>,
+        key_field: impl Into<String>,
+        options: BufferedSenderOptions,
+    ) -> (Self, IndexingEvents) {
+        let (commands, command_receiver) = mpsc::channel(options.channel_capacity.max(1));
+        let (events, receiver) = mpsc::unbounded_channel();
+        let worker = Worker::new(client, index_name.into(), options, command_receiver, events);
+        let sender = Self {
+            commands,
+            task: tokio::spawn(worker.run()),
+            key_field: key_field.into(),
+            _document: PhantomData,
+        };
+        (sender, IndexingEvents { receiver })
+    }
+
+    /// Queue an action
+    pub async fn send(&self, action: IndexAction<T>) -> Result<()> {
+        let action = IndexAction::new(action.action, serde_json::to_value(&action.document)?);
+        let key = match action.document.get(&self.key_field) {
+            Some(Value::String(key)) => key.clone(),
+            Some(Value::Number(key)) => key.to_string(),
+            _ => {
+                return Err(Error::SearchError(format!(
+                    "document is missing key field {}",
+                    self.key_field
+                )))
+            }
+        };
+        self.commands
+            .send(Command::Action(PendingAction {
+                key,
+                action,
+                attempts: 0,
+            }))
+            .await
+            .map_err(|_| closed())
+    }
+
+    /// Queue an upload action
+    pub async fn upload(&self, document: T) -> Result<()> {
+        self.send(IndexAction::upload(document)).await
+    }
+
+    /// Queue a merge action
+    pub async fn merge(&self, document: T) -> Result<()> {
+        self.send(IndexAction::merge(document)).await
+    }
+
+    /// Queue a mergeOrUpload action
+    pub async fn merge_or_upload(&self, document: T) -> Result<()> {
+        self.send(IndexAction::merge_or_upload(document)).await
+    }
+
+    /// Queue a delete action
+    pub async fn delete(&self, document: T) -> Result<()> {
+        self.send(IndexAction::delete(document)).await
+    }
+
+    /// Send everything queued so far and wait until it has succeeded or permanently failed
+    pub async fn flush(&self) -> Result<()> {
+        let (done, wait) = oneshot::channel();
+        self.commands
+            .send(Command::Flush(done))
+            .await
+            .map_err(|_| closed())?;
+        wait.await.map_err(|_| closed())
+    }
+
+    /// Flush every pending action and stop the background task
+    pub async fn close(self) -> Result<()> {
+        drop(self.commands);
+        self.task
+            .await
+            .map_err(|err| Error::SearchError(format!("buffered sender task failed: {}", err)))
+    }
+}
+
+fn closed() -> Error {
+    Error::SearchError("buffered sender is closed".to_string())
+}
+
+struct PendingAction {
+    key: String,
+    action: IndexAction<Value>,
+    attempts: u32,
+}
+
+type BatchOutcome = std::result::Result<(Id, Result<Vec<IndexingResult>>), JoinError>;
+
+struct Worker {
+    client: AzureSearchClient,
+    index_name: String,
+    options: BufferedSenderOptions,
+    commands: mpsc::Receiver<Command>,
+    events: mpsc::UnboundedSender<IndexingEvent>,
+    pending: VecDeque<PendingAction>,
+    retries: Vec<(Instant, PendingAction)>,
+    in_flight: JoinSet<Result<Vec<IndexingResult>>>,
+    /// Actions of each in-flight batch, by task
+    in_flight_actions: HashMap<Id, Vec<PendingAction>>,
+    flush_waiters: Vec<oneshot::Sender<()>>,
+}
+
+impl Worker {
+    fn new(
+        client: AzureSearchClient,
+        index_name: String,
+        options: BufferedSenderOptions,
+        commands: mpsc::Receiver<Command>,
+        events: mpsc::UnboundedSender<IndexingEvent>,
+    ) -> Self {
+        Self {
+            // Retries are scheduled per action by the worker instead
+            client: client.with_retry_policy(RetryPolicy::none()),
+            index_name,
+            options,
+            commands,
+            events,
+            pending: VecDeque::new(),
+            retries: Vec::new(),
+            in_flight: JoinSet::new(),
+            in_flight_actions: HashMap::new(),
+            flush_waiters: Vec::new(),
+        }
+    }
+
+    async fn run(mut self) {
+        let mut open = true;
+        let mut flush_deadline = Instant::now() + self.options.flush_interval;
+
+        loop {
+            self.promote_due_retries();
+
+            let flushing = !open || !self.flush_waiters.is_empty();
+            let batch_size = self.options.batch_size.max(1);
+            while self.in_flight.len() < self.options.max_in_flight.max(1)
+                && (self.pending.len() >= batch_size || (flushing && !self.pending.is_empty()))
+            {
+                self.dispatch();
+            }
+
+            if self.is_idle() {
+                for waiter in self.flush_waiters.drain(..) {
+                    let _ = waiter.send(());
+                }
+                if !open {
+                    return;
+                }
+            }
+
+            let retry_at = self.retries.iter().map(|(at, _)| *at).min();
+            let wake_at = match retry_at {
+                Some(at) => at.min(flush_deadline),
+                None => flush_deadline,
+            };
+
+            tokio::select! {
+                command = self.commands.recv(), if open => match command {
+                    Some(Command::Action(action)) => self.pending.push_back(action),
+                    Some(Command::Flush(done)) => self.flush_waiters.push(done),
+                    None => open = false,
+                },
+                Some(outcome) = self.in_flight.join_next_with_id(), if !self.in_flight.is_empty() => {
+                    self.finish(outcome);
+                }
+                _ = tokio::time::sleep_until(wake_at) => {
+                    if Instant::now() >= flush_deadline {
+                        flush_deadline = Instant::now() + self.options.flush_interval;
+                        while !self.pending.is_empty()
+                            && self.in_flight.len() < self.options.max_in_flight.max(1)
+                        {
+                            self.dispatch();
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    fn is_idle(&self) -> bool {
+        self.pending.is_empty() && self.retries.is_empty() && self.in_flight.is_empty()
+    }
+
+    /// Move retries whose backoff has elapsed to the front of the queue
+    fn promote_due_retries(&mut self) {
+        let now = Instant::now();
+        let mut index = 0;
+        while index < self.retries.len() {
+            if self.retries[index].0 <= now {
+                let (_, action) = self.retries.swap_remove(index);
+                self.pending.push_front(action);
+            } else {
+                index += 1;
+            }
+        }
+    }
+
+    /// Send up to one batch of pending actions
+    fn dispatch(&mut self) {
+        let count = self.pending.len().min(self.options.batch_size.max(1));
+        let mut actions: Vec<PendingAction> = self.pending.drain(..count).collect();
+        for action in &mut actions {
+            action.attempts += 1;
+        }
+
+        let batch: IndexBatch<Value> = actions
+            .iter()
+            .map(|pending| pending.action.clone())
+            .collect();
+        let client = self.client.clone();
+        let index_name = self.index_name.clone();
+        let task = self.in_flight.spawn(async move {
+            client
+                .index_documents(&index_name, batch)
+                .await
+                .map(|result| result.value)
+        });
+        self.in_flight_actions.insert(task.id(), actions);
+    }
+
+    /// Handle a finished batch task
+    fn finish(&mut self, outcome: BatchOutcome) {
+        let (id, result) = match outcome {
+            Ok((id, result)) => (id, result),
+            // The batch may or may not have been applied, so it isn't retried
+            Err(err) => {
+                let actions = self.in_flight_actions.remove(&err.id()).unwrap_or_default();
+                for pending in actions {
+                    let result = IndexingResult {
+                        key: pending.key,
+                        status: false,
+                        error_message: Some(format!("batch task failed: {}", err)),
+                        status_code: 0,
+                    };
+                    let _ = self.events.send(IndexingEvent::PermanentlyFailed {
+                        action: pending.action,
+                        result,
+                        attempts: pending.attempts,
+                    });
+                }
+                return;
+            }
+        };
+        let actions = self.in_flight_actions.remove(&id).unwrap_or_default();
+        self.complete(actions, result);
+    }
+
+    /// Report results of a finished batch and schedule retries
+    fn complete(&mut self, actions: Vec<PendingAction>, result: Result<Vec<IndexingResult>>) {
+        let results: Vec<IndexingResult> = match result {
+            Ok(results) if results.len() == actions.len() => results,
+            Ok(results) => actions
+                .iter()
+                .map(|pending| find_result(&results, &pending.key))
+                .collect(),
+            // Only the actions of the failed requests of a split batch failed
+            Err(Error::BatchFailed { result, failures }) => actions
+                .iter()
+                .enumerate()
+                .map(|(position, pending)| {
+                    match failures
+                        .iter()
+                        .find(|failure| failure.actions.contains(&position))
+                    {
+                        Some(failure) => failed_result(&pending.key, &failure.error),
+                        None => find_result(&result.value, &pending.key),
+                    }
+                })
+                .collect(),
+            Err(err) => actions
+                .iter()
+                .map(|pending| failed_result(&pending.key, &err))
+                .collect(),
+        };
+
+        for (pending, result) in actions.into_iter().zip(results) {
+            let event = if result.status {
+                IndexingEvent::Succeeded {
+                    action: pending.action,
+                    result,
+                }
+            } else if result.is_retriable() && pending.attempts < self.options.max_attempts {
+                let delay = self.options.retry_policy.delay_for(pending.attempts, None);
+                let event = IndexingEvent::Failed {
+                    action: pending.action.clone(),
+                    result,
+                    attempts: pending.attempts,
+                };
+                self.retries.push((Instant::now() + delay, pending));
+                event
+            } else {
+                IndexingEvent::PermanentlyFailed {
+                    action: pending.action,
+                    result,
+                    attempts: pending.attempts,
+                }
+            };
+            // Nobody listening for events is not an error
+            let _ = self.events.send(event);
+        }
+    }
+}
+
+/// Result for `key` in a response, or a failure if the service didn't return one
+fn find_result(results: &[IndexingResult], key: &str) -> IndexingResult {
+    results
+        .iter()
+        .find(|result| result.key == key)
+        .cloned()
+        .unwrap_or_else(|| IndexingResult {
+            key: key.to_string(),
+            status: false,
+            error_message: Some("no result returned for document".to_string()),
+            status_code: 0,
+        })
+}
+
+/// Result for an action whose request failed as a whole
+fn failed_result(key: &str, err: &Error) -> IndexingResult {
+    IndexingResult {
+        key: key.to_string(),
+        status: false,
+        error_message: Some(err.to_string()),
+        // Report request-level transient failures as 503 so they are retried
+        status_code: if err.is_retriable() {
+            503
+        } else {
+            err.status().map(|status| status.as_u16()).unwrap_or(0)
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use std::sync::Mutex;
+
+    use serde_json::json;
+
+    use super::*;
+    use crate::test_util::{TestResponse, TestServer};
+
+    /// Index endpoint failing keys named `fail-<status>` with that status on
+    /// their first attempt, and keys named `always-<status>` on every attempt
+    async fn index_endpoint() -> TestServer {
+        let attempts = Mutex::new(HashMap::<String, u32>::new());
+        TestServer::start(move |request| {
+            let body: Value = serde_json::from_str(&request.body).unwrap();
+            let mut attempts = attempts.lock().unwrap();
+            let value: Vec<Value> = body["value"]
+                .as_array()
+                .unwrap()
+                .iter()
+                .map(|action| {
+                    let key = action["id"].as_str().unwrap();
+                    let attempt = attempts.entry(key.to_string()).or_default();
+                    *attempt += 1;
+                    let failure: Option<u16> = match key.split_once('-') {
+                        Some(("fail", status)) if *attempt == 1 => status.parse().ok(),
+                        Some(("always", status)) => status.parse().ok(),
+                        _ => None,
+                    };
+                    match failure {
+                        Some(status) => json!({
+                            "key": key,
+                            "status": false,
+                            "errorMessage": "transient",
+                            "statusCode": status,
+                        }),
+                        None => json!({"key": key, "status": true, "statusCode": 200}),
+                    }
+                })
+                .collect();
+            TestResponse::json(207, json!({ "value": value }))
+        })
+        .await
+    }
+
+    fn options() -> BufferedSenderOptions {
+        BufferedSenderOptions {
+            batch_size: 100,
+            flush_interval: Duration::from_secs(600),
+            retry_policy: RetryPolicy::default()
+                .with_base_delay(Duration::from_millis(1))
+                .with_jitter(false),
+            ..Default::default()
+        }
+    }
+
+    fn sender(
+        server: &TestServer,
+        options: BufferedSenderOptions,
+    ) -> (BufferedSender<Value>, IndexingEvents) {
+        let client = AzureSearchClient::new(&server.url, "key", "2024-07-01", None).unwrap();
+        BufferedSender::new(client, "hotels", "id", options)
+    }
+
+    async fn next_event(events: &mut IndexingEvents) -> IndexingEvent {
+        tokio::time::timeout(Duration::from_secs(5), events.recv())
+            .await
+            .expect("timed out waiting for an event")
+            .expect("event stream ended")
+    }
+
+    fn batch_sizes(server: &TestServer) -> Vec<usize> {
+        server
+            .requests()
+            .iter()
+            .map(|request| {
+                let body: Value = serde_json::from_str(&request.body).unwrap();
+                body["value"].as_array().unwrap().len()
+            })
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn flushes_when_batch_is_full() {
+        let server = index_endpoint().await;
+        let (sender, mut events) = sender(
+            &server,
+            BufferedSenderOptions {
+                batch_size: 2,
+                ..options()
+            },
+        );
+
+        for id in 0..3 {
+            sender.upload(json!({"id": id.to_string()})).await.unwrap();
+        }
+        for _ in 0..2 {
+            assert!(matches!(
+                next_event(&mut events).await,
+                IndexingEvent::Succeeded { .. }
+            ));
+        }
+        // The third action waits for a full batch or the flush interval
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        assert_eq!(batch_sizes(&server), [2]);
+
+        sender.flush().await.unwrap();
+        assert_eq!(batch_sizes(&server), [2, 1]);
+    }
+
+    #[tokio::test]
+    async fn flushes_when_interval_elapses() {
+        let server = index_endpoint().await;
+        let (sender, mut events) = sender(
+            &server,
+            BufferedSenderOptions {
+                flush_interval: Duration::from_millis(50),
+                ..options()
+            },
+        );
+
+        sender.upload(json!({"id": "1"})).await.unwrap();
+        match next_event(&mut events).await {
+            IndexingEvent::Succeeded { result, .. } => assert_eq!(result.key, "1"),
+            other => panic!("unexpected event: {:?}", other),
+        }
+        assert_eq!(batch_sizes(&server), [1]);
+    }
+
+    #[tokio::test]
+    async fn retries_transient_failures() {
+        let server = index_endpoint().await;
+        let (sender, mut events) = sender(&server, options());
+
+        for key in ["fail-409", "fail-422", "fail-503", "ok"] {
+            sender.upload(json!({ "id": key })).await.unwrap();
+        }
+        sender.flush().await.unwrap();
+
+        let mut failed = Vec::new();
+        let mut succeeded = Vec::new();
+        for _ in 0..7 {
+            match next_event(&mut events).await {
+                IndexingEvent::Failed {
+                    result, attempts, ..
+                } => {
+                    assert_eq!(attempts, 1);
+                    failed.push(result.status_code);
+                }
+                IndexingEvent::Succeeded { result, .. } => succeeded.push(result.key),
+                other => panic!("unexpected event: {:?}", other),
+            }
+        }
+        failed.sort();
+        succeeded.sort();
+        assert_eq!(failed, [409, 422, 503]);
+        assert_eq!(succeeded, ["fail-409", "fail-422", "fail-503", "ok"]);
+        assert_eq!(batch_sizes(&server), [4, 3]);
+    }
+
+    #[tokio::test]
+    async fn gives_up_after_max_attempts() {
+        let server = index_endpoint().await;
+        let (sender, mut events) = sender(
+            &server,
+            BufferedSenderOptions {
+                max_attempts: 2,
+                ..options()
+            },
+        );
+
+        sender.upload(json!({"id": "always-503"})).await.unwrap();
+        sender.upload(json!({"id": "always-400"})).await.unwrap();
+        sender.flush().await.unwrap();
+
+        let mut outcomes = Vec::new();
+        for _ in 0..3 {
+            outcomes.push(match next_event(&mut events).await {
+                IndexingEvent::Failed {
+                    result, attempts, ..
+                } => ("failed", result.key, attempts),
+                IndexingEvent::PermanentlyFailed {
+                    result, attempts, ..
+                } => ("permanently failed", result.key, attempts),
+                other => panic!("unexpected event: {:?}", other),
+            });
+        }
+        outcomes.sort();
+        assert_eq!(
+            outcomes,
+            [
+                ("failed", "always-503".to_string(), 1),
+                ("permanently failed", "always-400".to_string(), 1),
+                ("permanently failed", "always-503".to_string(), 2),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn batch_errors_are_not_retried_by_the_client() {
+        let server = TestServer::start(|_| TestResponse::new(503, "")).await;
+        let (sender, mut events) = sender(
+            &server,
+            BufferedSenderOptions {
+                max_attempts: 2,
+                ..options()
+            },
+        );
+
+        sender.upload(json!({"id": "1"})).await.unwrap();
+        sender.close().await.unwrap();
+
+        assert!(matches!(
+            next_event(&mut events).await,
+            IndexingEvent::Failed { attempts: 1, .. }
+        ));
+        assert!(matches!(
+            next_event(&mut events).await,
+            IndexingEvent::PermanentlyFailed { attempts: 2, .. }
+        ));
+        // One request per sender attempt, none added by the client's retry policy
+        assert_eq!(server.request_count(), 2);
+    }
+
+    #[tokio::test]
+    async fn failed_chunk_of_a_split_batch_is_retried() {
+        // The client splits the sender's batch of 1500 actions into requests
+        // of 1000 and 500; the second request fails once
+        let failed = Mutex::new(false);
+        let server = TestServer::start(move |request| {
+            let body: Value = serde_json::from_str(&request.body).unwrap();
+            let actions = body["value"].as_array().unwrap();
+            let mut failed = failed.lock().unwrap();
+            if actions.len() == 500 && !*failed {
+                *failed = true;
+                return TestResponse::new(503, "");
+            }
+            let value: Vec<Value> = actions
+                .iter()
+                .map(|action| json!({"key": action["id"], "status": true, "statusCode": 200}))
+                .collect();
+            TestResponse::json(200, json!({ "value": value }))
+        })
+        .await;
+        let (sender, mut events) = sender(
+            &server,
+            BufferedSenderOptions {
+                batch_size: 1500,
+                ..options()
+            },
+        );
+
+        for id in 0..1500 {
+            sender.upload(json!({"id": id.to_string()})).await.unwrap();
+        }
+        sender.close().await.unwrap();
+
+        let mut succeeded = Vec::new();
+        let mut failed = Vec::new();
+        while let Some(event) = events.recv().await {
+            match event {
+                IndexingEvent::Succeeded { result, .. } => succeeded.push(result.key),
+                IndexingEvent::Failed {
+                    result, attempts, ..
+                } => {
+                    assert_eq!((result.status_code, attempts), (503, 1));
+                    failed.push(result.key.parse::<usize>().unwrap());
+                }
+                other => panic!("unexpected event: {:?}", other),
+            }
+        }
+        assert_eq!(failed, (1000..1500).collect::<Vec<_>>());
+        assert_eq!(succeeded.len(), 1500);
+        assert_eq!(batch_sizes(&server), [1000, 500, 500]);
+    }
+
+    #[tokio::test]
+    async fn close_drains_pending_actions() {
+        let server = index_endpoint().await;
+        let (sender, mut events) = sender(&server, options());
+
+        for id in 0..3 {
+            sender.upload(json!({"id": id.to_string()})).await.unwrap();
+        }
+        sender.close().await.unwrap();
+        assert_eq!(batch_sizes(&server), [3]);
+
+        let mut keys = Vec::new();
+        while let Some(event) = events.recv().await {
+            match event {
+                IndexingEvent::Succeeded { result, .. } => keys.push(result.key),
+                other => panic!("unexpected event: {:?}", other),
+            }
+        }
+        assert_eq!(keys, ["0", "1", "2"]);
+    }
+
+    #[tokio::test]
+    async fn documents_need_a_key() {
+        let server = index_endpoint().await;
+        let (sender, _events) = sender(&server, options());
+        assert!(sender.upload(json!({"name": "no key"})).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn failed_batch_task_is_reported() {
+        let client =
+            AzureSearchClient::new("http://127.0.0.1:1", "key", "2024-07-01", None).unwrap();
+        let (_commands, command_receiver) = mpsc::channel(1);
+        let (events, mut receiver) = mpsc::unbounded_channel();
+        let mut worker = Worker::new(
+            client,
+            "hotels".to_string(),
+            options(),
+            command_receiver,
+            events,
+        );
+
+        let task = worker
+            .in_flight
+            .spawn(async { panic!("serializer panicked") });
+        worker.in_flight_actions.insert(
+            task.id(),
+            vec![PendingAction {
+                key: "1".to_string(),
+                action: IndexAction::upload(json!({"id": "1"})),
+                attempts: 1,
+            }],
+        );
+        let outcome = worker.in_flight.join_next_with_id().await.unwrap();
+        worker.finish(outcome);
+
+        match receiver.try_recv().unwrap() {
+            IndexingEvent::PermanentlyFailed {
+                result, attempts, ..
+            } => {
+                assert_eq!(result.key, "1");
+                assert_eq!(attempts, 1);
+                assert!(result.error_message.unwrap().contains("panicked"));
+            }
+            other => panic!("unexpected event: {:?}", other),
+        }
+        assert!(worker.in_flight_actions.is_empty());
+    }
+}
@@ -1,6 +1,11 @@
-use crate::{client::AzureSearchClient, error::Result};
+use crate::{
+    client::AzureSearchClient,
+    error::{BatchFailure, Error, Result},
+    models::document::{IndexActionType, IndexBatch, IndexDocumentsOptions, IndexDocumentsResult},
+};
+use futures::StreamExt;
 use serde::{Deserialize, Serialize};
-use serde_json::json;
+use serde_json::value::RawValue;
 
 pub trait DocumentTrait {
     /// Get a document by ID
@@ -12,14 +17,59 @@ pub trait DocumentTrait {
     where
         T: for<'de> Deserialize<'de>;
 
+    /// Send a batch of upload, merge, mergeOrUpload and delete actions
+    fn index_documents<T>(
+        &self,
+        index_name: &str,
+        batch: IndexBatch<T>,
+    ) -> impl std::future::Future<Output = Result<IndexDocumentsResult>> + Send
+    where
+        T: Serialize + Send;
+
+    /// Send a batch of actions with explicit options
+    fn index_documents_with_options<T>(
+        &self,
+        index_name: &str,
+        batch: IndexBatch<T>,
+        options: &IndexDocumentsOptions,
+    ) -> impl std::future::Future<Output = Result<IndexDocumentsResult>> + Send
+    where
+        T: Serialize + Send;
+
     /// Upload or update documents in an index
     fn put_documents<T>(
         &self,
         index_name: &str,
         documents: Vec<T>,
-    ) -> impl std::future::Future<Output = Result<()>> + Send
+    ) -> impl std::future::Future<Output = Result<IndexDocumentsResult>> + Send
     where
         T: Serialize + Send;
+
+    /// Merge (partial) documents into existing documents
+    fn merge_documents<T>(
+        &self,
+        index_name: &str,
+        documents: Vec<T>,
+    ) -> impl std::future::Future<Output = Result<IndexDocumentsResult>> + Send
+    where
+        T: Serialize + Send;
+
+    /// Merge (partial) documents, uploading those that don't exist yet
+    fn merge_or_upload_documents<T>(
+        &self,
+        index_name: &str,
+        documents: Vec<T>,
+    ) -> impl std::future::Future<Output = Result<IndexDocumentsResult>> + Send
+    where
+        T: Serialize + Send;
+
+    /// Delete documents by key
+    fn delete_documents(
+        &self,
+        index_name: &str,
+        key_field: &str,
+        keys: Vec<String>,
+    ) -> impl std::future::Future<Output = Result<IndexDocumentsResult>> + Send;
 }
 
 impl DocumentTrait for AzureSearchClient {
@@ -33,17 +83,354 @@ impl DocumentTrait for AzureSearchClient {
             .await
     }
 
-    /// Upload or update documents in an index
-    async fn put_documents<T>(&self, index_name: &str, documents: Vec<T>) -> Result<()>
+    /// Send a batch of upload, merge, mergeOrUpload and delete actions
+    async fn index_documents<T>(
+        &self,
+        index_name: &str,
+        batch: IndexBatch<T>,
+    ) -> Result<IndexDocumentsResult>
+    where
+        T: Serialize + Send,
+    {
+        self.index_documents_with_options(index_name, batch, &IndexDocumentsOptions::default())
+            .await
+    }
+
+    /// Send a batch of actions with explicit options
+    ///
+    /// Batches exceeding `max_batch_size` actions or `max_batch_bytes` bytes are
+    /// split into several requests and their results are concatenated in order.
+    /// If some of these requests fail, [`Error::BatchFailed`] carries the
+    /// results of the others along with the positions of the failed actions.
+    async fn index_documents_with_options<T>(
+        &self,
+        index_name: &str,
+        batch: IndexBatch<T>,
+        options: &IndexDocumentsOptions,
+    ) -> Result<IndexDocumentsResult>
     where
         T: Serialize + Send,
     {
         let path = format!("indexes/{}/docs/index", index_name);
-        let body = json!({
-            "value": documents
-        });
-        self.send_request::<serde_json::Value, _>(reqwest::Method::POST, &path, Some(&body))
-            .await?;
-        Ok(())
+        let chunks = split_batch(batch, options)?;
+        let lengths: Vec<usize> = chunks.iter().map(|chunk| chunk.value.len()).collect();
+        let requests = chunks.len();
+
+        // A 207 Multi-Status response is a success; failures are reported per document
+        let outcomes: Vec<Result<IndexDocumentsResult>> = futures::stream::iter(chunks)
+            .map(|chunk| {
+                let path = &path;
+                async move {
+                    self.send_request(reqwest::Method::POST, path, Some(&chunk))
+                        .await
+                }
+            })
+            .buffered(options.max_concurrency.max(1))
+            .collect()
+            .await;
+
+        let mut result = IndexDocumentsResult::default();
+        let mut failures = Vec::new();
+        let mut start = 0;
+        for (outcome, length) in outcomes.into_iter().zip(lengths) {
+            match outcome {
+                Ok(response) => result.value.extend(response.value),
+                Err(error) => failures.push(BatchFailure {
+                    actions: start..start + length,
+                    error,
+                }),
+            }
+            start += length;
+        }
+
+        if !failures.is_empty() {
+            // An unsplit batch has no partial results to report
+            if requests == 1 {
+                return Err(failures.remove(0).error);
+            }
+            return Err(Error::BatchFailed { result, failures });
+        }
+
+        if options.throw_on_any_failure && !result.is_success() {
+            return Err(Error::IndexingFailed {
+                failed: result.failed().cloned().collect(),
+            });
+        }
+        Ok(result)
+    }
+
+    /// Upload or update documents in an index
+    async fn put_documents<T>(
+        &self,
+        index_name: &str,
+        documents: Vec<T>,
+    ) -> Result<IndexDocumentsResult>
+    where
+        T: Serialize + Send,
+    {
+        let batch = IndexBatch::with_action(IndexActionType::Upload, documents);
+        self.index_documents(index_name, batch).await
+    }
+
+    /// Merge (partial) documents into existing documents
+    async fn merge_documents<T>(
+        &self,
+        index_name: &str,
+        documents: Vec<T>,
+    ) -> Result<IndexDocumentsResult>
+    where
+        T: Serialize + Send,
+    {
+        let batch = IndexBatch::with_action(IndexActionType::Merge, documents);
+        self.index_documents(index_name, batch).await
+    }
+
+    /// Merge (partial) documents, uploading those that don't exist yet
+    async fn merge_or_upload_documents<T>(
+        &self,
+        index_name: &str,
+        documents: Vec<T>,
+    ) -> Result<IndexDocumentsResult>
+    where
+        T: Serialize + Send,
+    {
+        let batch = IndexBatch::with_action(IndexActionType::MergeOrUpload, documents);
+        self.index_documents(index_name, batch).await
+    }
+
+    /// Delete documents by key
+    async fn delete_documents(
+        &self,
+        index_name: &str,
+        key_field: &str,
+        keys: Vec<String>,
+    ) -> Result<IndexDocumentsResult> {
+        let batch = IndexBatch::delete_keys(key_field, keys);
+        self.index_documents(index_name, batch).await
+    }
+}
+
+/// Pre-serialized batch body
+#[derive(Serialize)]
+struct RawBatch {
+    value: Vec<Box<RawValue>>,
+}
+
+/// Size of `{"value":[]}` around the serialized actions
+const BATCH_ENVELOPE_BYTES: usize = r#"{"value":[]}"#.len();
+
+/// Split a batch into request bodies within the configured count and size limits
+fn split_batch<T: Serialize>(
+    batch: IndexBatch<T>,
+    options: &IndexDocumentsOptions,
+) -> Result<Vec<RawBatch>> {
+    let max_size = options.max_batch_size.max(1);
+    let mut chunks = Vec::new();
+    let mut current = Vec::new();
+    let mut current_bytes = BATCH_ENVELOPE_BYTES;
+
+    for (position, action) in batch.value.iter().enumerate() {
+        let action = serde_json::value::to_raw_value(action)?;
+        let size = action.get().len();
+        if BATCH_ENVELOPE_BYTES + size > options.max_batch_bytes {
+            return Err(Error::DocumentTooLarge {
+                position,
+                size,
+                limit: options.max_batch_bytes,
+            });
+        }
+
+        // One separator byte between actions
+        let added = if current.is_empty() { size } else { size + 1 };
+        if current.len() == max_size || current_bytes + added > options.max_batch_bytes {
+            chunks.push(RawBatch {
+                value: std::mem::take(&mut current),
+            });
+            current_bytes = BATCH_ENVELOPE_BYTES + size;
+        } else {
+            current_bytes += added;
+        }
+        current.push(action);
+    }
+
+    if !current.is_empty() {
+        chunks.push(RawBatch { value: current });
+    }
+    Ok(chunks)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::document::IndexAction;
+    use crate::test_util::{TestResponse, TestServer};
+    use serde_json::{json, Value};
+
+    fn documents(count: usize, text_len: usize) -> IndexBatch<Value> {
+        IndexBatch::upload(
+            (0..count).map(|id| json!({"id": id.to_string(), "text": "x".repeat(text_len)})),
+        )
+    }
+
+    fn chunk_sizes(chunks: &[RawBatch]) -> Vec<usize> {
+        chunks.iter().map(|chunk| chunk.value.len()).collect()
+    }
+
+    fn body_len(chunk: &RawBatch) -> usize {
+        serde_json::to_string(chunk).unwrap().len()
+    }
+
+    #[test]
+    fn split_by_count() {
+        let options = IndexDocumentsOptions::default().max_batch_size(2);
+        let chunks = split_batch(documents(5, 1), &options).unwrap();
+        assert_eq!(chunk_sizes(&chunks), [2, 2, 1]);
+
+        let chunks = split_batch(documents(4, 1), &options).unwrap();
+        assert_eq!(chunk_sizes(&chunks), [2, 2]);
+
+        let chunks = split_batch(IndexBatch::<Value>::new(), &options).unwrap();
+        assert!(chunks.is_empty());
+    }
+
+    #[test]
+    fn split_by_bytes() {
+        let action_len = serde_json::to_string(&documents(1, 100).value[0])
+            .unwrap()
+            .len();
+        // Room for exactly two actions and their separator
+        let limit = BATCH_ENVELOPE_BYTES + 2 * action_len + 1;
+        let options = IndexDocumentsOptions::default().max_batch_bytes(limit);
+
+        let chunks = split_batch(documents(5, 100), &options).unwrap();
+        assert_eq!(chunk_sizes(&chunks), [2, 2, 1]);
+        assert_eq!(body_len(&chunks[0]), limit);
+        assert!(chunks.iter().all(|chunk| body_len(chunk) <= limit));
+
+        let options = options.max_batch_bytes(limit - 1);
+        let chunks = split_batch(documents(3, 100), &options).unwrap();
+        assert_eq!(chunk_sizes(&chunks), [1, 1, 1]);
+    }
+
+    #[test]
+    fn split_preserves_order() {
+        let options = IndexDocumentsOptions::default().max_batch_size(2);
+        let chunks = split_batch(documents(3, 1), &options).unwrap();
+        let ids: Vec<String> = chunks
+            .iter()
+            .flat_map(|chunk| &chunk.value)
+            .map(|action| serde_json::from_str::<Value>(action.get()).unwrap()["id"].to_string())
+            .collect();
+        assert_eq!(ids, [r#""0""#, r#""1""#, r#""2""#]);
+    }
+
+    #[test]
+    fn document_over_byte_limit() {
+        let mut batch = documents(2, 10);
+        batch.push(IndexAction::upload(
+            json!({"id": "big", "text": "x".repeat(1000)}),
+        ));
+        let options = IndexDocumentsOptions::default().max_batch_bytes(500);
+
+        match split_batch(batch, &options) {
+            Err(Error::DocumentTooLarge {
+                position,
+                size,
+                limit,
+            }) => {
+                assert_eq!(position, 2);
+                assert!(size > 1000);
+                assert_eq!(limit, 500);
+            }
+            other => panic!("unexpected result: {:?}", other.map(|chunks| chunks.len())),
+        }
+    }
+
+    /// Answers each batch with per-key results, failing batches containing key `"fail"`
+    async fn index_endpoint() -> TestServer {
+        TestServer::start(|request| {
+            let body: Value = serde_json::from_str(&request.body).unwrap();
+            let keys: Vec<&str> = body["value"]
+                .as_array()
+                .unwrap()
+                .iter()
+                .map(|action| action["id"].as_str().unwrap())
+                .collect();
+            if keys.contains(&"fail") {
+                return TestResponse::json(400, json!({"error": {"message": "bad batch"}}));
+            }
+            let value: Vec<Value> = keys
+                .iter()
+                .map(|key| json!({"key": key, "status": true, "statusCode": 201}))
+                .collect();
+            TestResponse::json(200, json!({ "value": value }))
+        })
+        .await
+    }
+
+    #[tokio::test]
+    async fn split_batch_results_are_concatenated() {
+        let server = index_endpoint().await;
+        let client = AzureSearchClient::new(&server.url, "key", "2024-07-01", None).unwrap();
+        let options = IndexDocumentsOptions::default()
+            .max_batch_size(2)
+            .max_concurrency(2);
+
+        let result = client
+            .index_documents_with_options("hotels", documents(5, 1), &options)
+            .await
+            .unwrap();
+        let keys: Vec<&str> = result
+            .succeeded()
+            .map(|result| result.key.as_str())
+            .collect();
+        assert_eq!(keys, ["0", "1", "2", "3", "4"]);
+        assert_eq!(server.request_count(), 3);
+    }
+
+    #[tokio::test]
+    async fn failed_chunk_keeps_committed_results() {
+        let server = index_endpoint().await;
+        let client = AzureSearchClient::new(&server.url, "key", "2024-07-01", None).unwrap();
+        let options = IndexDocumentsOptions::default()
+            .max_batch_size(2)
+            .max_concurrency(2);
+
+        let mut batch = documents(2, 1);
+        batch.push(IndexAction::upload(json!({"id": "fail"})));
+        batch.push(IndexAction::upload(json!({"id": "3"})));
+        batch.push(IndexAction::upload(json!({"id": "4"})));
+
+        match client
+            .index_documents_with_options("hotels", batch, &options)
+            .await
+        {
+            Err(Error::BatchFailed { result, failures }) => {
+                let keys: Vec<&str> = result
+                    .succeeded()
+                    .map(|result| result.key.as_str())
+                    .collect();
+                assert_eq!(keys, ["0", "1", "4"]);
+                assert_eq!(failures.len(), 1);
+                assert_eq!(failures[0].actions, 2..4);
+                assert_eq!(
+                    failures[0].error.status(),
+                    Some(reqwest::StatusCode::BAD_REQUEST)
+                );
+            }
+            other => panic!("unexpected result: {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn unsplit_batch_returns_request_error() {
+        let server = index_endpoint().await;
+        let client = AzureSearchClient::new(&server.url, "key", "2024-07-01", None).unwrap();
+
+        let err = client
+            .index_documents("hotels", IndexBatch::upload([json!({"id": "fail"})]))
+            .await
+            .unwrap_err();
+        assert_eq!(err.status(), Some(reqwest::StatusCode::BAD_REQUEST));
     }
 }
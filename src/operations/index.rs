@@ -0,0 +1,247 @@
+use serde::Deserialize;
+
+use crate::{
+    client::AzureSearchClient,
+    error::Result,
+    models::{index::SearchIndex, stats::IndexStatistics},
+};
+
+pub trait IndexTrait {
+    /// Create a new index
+    ///
+    /// Fails with [`crate::ValidationError`] without contacting the service if
+    /// a vector field or profile references an undefined configuration.
+    fn create_index(
+        &self,
+        index: &SearchIndex,
+    ) -> impl std::future::Future<Output = Result<SearchIndex>> + Send;
+
+    /// Create an index or update its definition
+    ///
+    /// Adding analyzers, tokenizers, token filters or char filters to an
+    /// existing index requires `allow_index_downtime`, which takes the index
+    /// offline for a few seconds. The vector search references are checked
+    /// as in [`IndexTrait::create_index`].
+    fn create_or_update_index(
+        &self,
+        index: &SearchIndex,
+        allow_index_downtime: bool,
+    ) -> impl std::future::Future<Output = Result<SearchIndex>> + Send;
+
+    /// Delete an index and all its documents
+    fn delete_index(
+        &self,
+        index_name: &str,
+    ) -> impl std::future::Future<Output = Result<()>> + Send;
+
+    /// Get the definition of an index
+    fn get_index(
+        &self,
+        index_name: &str,
+    ) -> impl std::future::Future<Output = Result<SearchIndex>> + Send;
+
+    /// List the definitions of all indexes
+    fn list_indexes(&self) -> impl std::future::Future<Output = Result<Vec<SearchIndex>>> + Send;
+
+    /// Get the document count and storage size of an index
+    fn get_index_statistics(
+        &self,
+        index_name: &str,
+    ) -> impl std::future::Future<Output = Result<IndexStatistics>> + Send;
+
+    /// Check that a semantic configuration exists before passing it to
+    /// [`crate::operations::search::SearchTrait::semantic_search`]
+    fn validate_semantic_configuration(
+        &self,
+        index_name: &str,
+        semantic_configuration: &str,
+    ) -> impl std::future::Future<Output = Result<()>> + Send;
+}
+
+impl IndexTrait for AzureSearchClient {
+    /// Create a new index
+    ///
+    /// Fails with [`crate::ValidationError`] without contacting the service if
+    /// a vector field or profile references an undefined configuration.
+    async fn create_index(&self, index: &SearchIndex) -> Result<SearchIndex> {
+        index.validate_vector_search()?;
+        self.send_request::<SearchIndex, SearchIndex>(reqwest::Method::POST, "indexes", Some(index))
+            .await
+    }
+
+    /// Create an index or update its definition
+    ///
+    /// Adding analyzers, tokenizers, token filters or char filters to an
+    /// existing index requires `allow_index_downtime`, which takes the index
+    /// offline for a few seconds. The vector search references are checked
+    /// as in [`IndexTrait::create_index`].
+    async fn create_or_update_index(
+        &self,
+        index: &SearchIndex,
+        allow_index_downtime: bool,
+    ) -> Result<SearchIndex> {
+        index.validate_vector_search()?;
+        let path = format!("indexes/{}", index.name);
+        let query: &[(&str, &str)] = if allow_index_downtime {
+            &[("allowIndexDowntime", "true")]
+        } else {
+            &[]
+        };
+        let updated = self
+            .send_request_with_query::<Option<SearchIndex>, SearchIndex>(
+                reqwest::Method::PUT,
+                &path,
+                query,
+                Some(index),
+            )
+            .await?;
+        // The service may answer an update with 204 No Content
+        match updated {
+            Some(updated) => Ok(updated),
+            None => self.get_index(&index.name).await,
+        }
+    }
+
+    /// Delete an index and all its documents
+    async fn delete_index(&self, index_name: &str) -> Result<()> {
+        let path = format!("indexes/{}", index_name);
+        self.send_request::<(), ()>(reqwest::Method::DELETE, &path, None)
+            .await
+    }
+
+    /// Get the definition of an index
+    async fn get_index(&self, index_name: &str) -> Result<SearchIndex> {
+        let path = format!("indexes/{}", index_name);
+        self.send_request::<SearchIndex, ()>(reqwest::Method::GET, &path, None)
+            .await
+    }
+
+    /// List the definitions of all indexes
+    async fn list_indexes(&self) -> Result<Vec<SearchIndex>> {
+        let list = self
+            .send_request::<IndexList, ()>(reqwest::Method::GET, "indexes", None)
+            .await?;
+        Ok(list.value)
+    }
+
+    /// Get the document count and storage size of an index
+    async fn get_index_statistics(&self, index_name: &str) -> Result<IndexStatistics> {
+        let path = format!("indexes/{}/stats", index_name);
+        self.send_request::<IndexStatistics, ()>(reqwest::Method::GET, &path, None)
+            .await
+    }
+
+    /// Check that a semantic configuration exists before passing it to
+    /// [`crate::operations::search::SearchTrait::semantic_search`]
+    async fn validate_semantic_configuration(
+        &self,
+        index_name: &str,
+        semantic_configuration: &str,
+    ) -> Result<()> {
+        let index = self.get_index(index_name).await?;
+        index.validate_semantic_configuration(semantic_configuration)?;
+        Ok(())
+    }
+}
+
+/// Response body of the list indexes request
+#[derive(Deserialize)]
+struct IndexList {
+    value: Vec<SearchIndex>,
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::error::{Error, ValidationError};
+    use crate::models::index::SearchField;
+    use crate::test_util::{TestResponse, TestServer};
+
+    #[tokio::test]
+    async fn allow_index_downtime_is_a_query_parameter() {
+        let server = TestServer::start(|request| match request.method.as_str() {
+            // An update may be answered without content
+            "PUT" => TestResponse::new(204, ""),
+            _ => TestResponse::json(200, json!({ "name": "hotels", "fields": [] })),
+        })
+        .await;
+        let client = AzureSearchClient::new(&server.url, "key", "2024-07-01", None).unwrap();
+        let index = SearchIndex::new("hotels", vec![SearchField::searchable("name")]);
+
+        let updated = client.create_or_update_index(&index, true).await.unwrap();
+        assert_eq!(updated.name, "hotels");
+        client.create_or_update_index(&index, false).await.unwrap();
+
+        let requests = server.requests();
+        assert_eq!(
+            requests[0].path,
+            "/indexes/hotels?api-version=2024-07-01&allowIndexDowntime=true"
+        );
+        assert_eq!(requests[1].method, "GET");
+        assert_eq!(requests[2].path, "/indexes/hotels?api-version=2024-07-01");
+    }
+
+    #[tokio::test]
+    async fn dangling_vector_profiles_are_rejected_before_sending() {
+        let server = TestServer::start(|_| TestResponse::new(500, "")).await;
+        let client = AzureSearchClient::new(&server.url, "key", "2024-07-01", None).unwrap();
+        let index = SearchIndex::new(
+            "hotels",
+            vec![SearchField::vector("embedding", 3, "missing")],
+        );
+
+        let err = client.create_index(&index).await.unwrap_err();
+        assert!(matches!(
+            err,
+            Error::Validation(ValidationError::UnknownVectorSearchProfile { .. })
+        ));
+        let err = client
+            .create_or_update_index(&index, false)
+            .await
+            .unwrap_err();
+        assert!(matches!(err, Error::Validation(_)));
+        assert_eq!(server.request_count(), 0);
+    }
+
+    #[tokio::test]
+    async fn semantic_configuration_is_checked_against_the_index() {
+        let server = TestServer::start(|_| {
+            TestResponse::json(
+                200,
+                json!({
+                    "name": "hotels",
+                    "fields": [{ "name": "description", "type": "Edm.String" }],
+                    "semantic": {
+                        "configurations": [{
+                            "name": "default",
+                            "prioritizedFields": {
+                                "prioritizedContentFields": [{ "fieldName": "description" }]
+                            }
+                        }]
+                    }
+                }),
+            )
+        })
+        .await;
+        let client = AzureSearchClient::new(&server.url, "key", "2024-07-01", None).unwrap();
+
+        client
+            .validate_semantic_configuration("hotels", "default")
+            .await
+            .unwrap();
+        let err = client
+            .validate_semantic_configuration("hotels", "missing")
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            err,
+            Error::Validation(ValidationError::UnknownSemanticConfiguration { name }) if name == "missing"
+        ));
+        assert_eq!(
+            server.requests()[0].path,
+            "/indexes/hotels?api-version=2024-07-01"
+        );
+    }
+}
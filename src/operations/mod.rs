@@ -1,4 +1,10 @@
 //! Operations for interacting with Azure AI Search
 
+pub mod analyze;
+pub mod autocomplete;
+pub mod buffered_sender;
 pub mod document;
+pub mod index;
 pub mod search;
+pub mod service;
+pub mod suggest;
@@ -1,8 +1,17 @@
+use futures::{Stream, TryStreamExt};
+use serde::Deserialize;
+use serde_json::{value::RawValue, Map, Value};
+use tokio::task::JoinHandle;
+
 use crate::{
     client::AzureSearchClient,
-    error::Result,
+    error::{Error, Result},
     models::{
-        search::{SearchRequest, SearchResponse, VectorKind},
+        search::{
+            CoveragePolicy, CoverageWarning, SearchPagingOptions, SearchRequest, SearchResponse,
+            SearchResult,
+        },
+        vector::VectorQuery,
         QueryType,
     },
 };
@@ -15,20 +24,38 @@ pub trait SearchTrait {
         request: &SearchRequest,
     ) -> impl std::future::Future<Output = Result<SearchResponse<T>>> + Send;
 
+    /// Stream every page of results, following `@search.nextPageParameters`
+    fn search_pages<T: serde::de::DeserializeOwned + Send + 'static>(
+        &self,
+        index_name: &str,
+        request: SearchRequest,
+        options: SearchPagingOptions,
+    ) -> impl Stream<Item = Result<SearchResponse<T>>> + Send + 'static;
+
+    /// Stream every result across all pages
+    fn search_stream<T: serde::de::DeserializeOwned + Send + 'static>(
+        &self,
+        index_name: &str,
+        request: SearchRequest,
+        options: SearchPagingOptions,
+    ) -> impl Stream<Item = Result<SearchResult<T>>> + Send + 'static;
+
     /// Execute a vector search query
     fn vector_search<T: serde::de::DeserializeOwned>(
         &self,
         index_name: &str,
-        vector_queries: Vec<VectorKind>,
+        vector_queries: impl IntoIterator<Item = impl Into<VectorQuery>> + Send,
     ) -> impl std::future::Future<Output = Result<SearchResponse<T>>> + Send;
 
     /// Execute a semantic search query (optionally with vector search)
+    ///
+    /// `VectorKind` queries convert with `.into()`.
     fn semantic_search<T: serde::de::DeserializeOwned>(
         &self,
         index_name: &str,
         query: &str,
         semantic_configuration: &str,
-        vector_queries: Option<Vec<VectorKind>>,
+        vector_queries: Option<Vec<VectorQuery>>,
     ) -> impl std::future::Future<Output = Result<SearchResponse<T>>> + Send;
 
     /// Execute a hybrid search combining semantic and vector search
@@ -37,7 +64,7 @@ pub trait SearchTrait {
         index_name: &str,
         query: &str,
         semantic_configuration: &str,
-        vector_queries: Vec<VectorKind>,
+        vector_queries: impl IntoIterator<Item = impl Into<VectorQuery>> + Send,
     ) -> impl std::future::Future<Output = Result<SearchResponse<T>>> + Send;
 }
 
@@ -49,34 +76,84 @@ impl SearchTrait for AzureSearchClient {
         request: &SearchRequest,
     ) -> Result<SearchResponse<T>> {
         let path = format!("indexes/{}/docs/search", index_name);
-        self.send_request::<SearchResponse<T>, SearchRequest>(
-            reqwest::Method::POST,
-            &path,
-            Some(request),
-        )
-        .await
+        let response = self
+            .send_request::<SearchResponse<T>, SearchRequest>(
+                reqwest::Method::POST,
+                &path,
+                Some(request),
+            )
+            .await?;
+        apply_coverage_policy(self.coverage_policy(), response, request.minimum_coverage)
+    }
+
+    /// Stream every page of results, following `@search.nextPageParameters`
+    ///
+    /// The parameters of each follow-up request are merged into the previous
+    /// request as raw JSON, so parameters this crate doesn't model are kept.
+    fn search_pages<T: serde::de::DeserializeOwned + Send + 'static>(
+        &self,
+        index_name: &str,
+        request: SearchRequest,
+        options: SearchPagingOptions,
+    ) -> impl Stream<Item = Result<SearchResponse<T>>> + Send + 'static {
+        let state = serde_json::to_value(&request)
+            .map_err(Error::from)
+            .map(|request| {
+                let mut request = match request {
+                    Value::Object(request) => request,
+                    _ => Map::new(),
+                };
+                if let Some(remaining) = options.max_results {
+                    limit_top(&mut request, remaining);
+                }
+                PageState {
+                    client: self.clone(),
+                    index_name: index_name.to_string(),
+                    next: Some(request),
+                    prefetched: None,
+                    remaining: options.max_results,
+                    prefetch: options.prefetch,
+                }
+            });
+        futures::stream::once(futures::future::ready(state))
+            .map_ok(|state| futures::stream::try_unfold(state, PageState::next_page))
+            .try_flatten()
+    }
+
+    /// Stream every result across all pages
+    fn search_stream<T: serde::de::DeserializeOwned + Send + 'static>(
+        &self,
+        index_name: &str,
+        request: SearchRequest,
+        options: SearchPagingOptions,
+    ) -> impl Stream<Item = Result<SearchResult<T>>> + Send + 'static {
+        self.search_pages(index_name, request, options)
+            .map_ok(|page| futures::stream::iter(page.value.into_iter().map(Ok)))
+            .try_flatten()
     }
 
     /// Execute a vector search query
     async fn vector_search<T: serde::de::DeserializeOwned>(
         &self,
         index_name: &str,
-        vector_queries: Vec<VectorKind>,
+        vector_queries: impl IntoIterator<Item = impl Into<VectorQuery>> + Send,
     ) -> Result<SearchResponse<T>> {
         let request = SearchRequest {
-            vector_queries: Some(vector_queries),
+            vector_queries: Some(vector_queries.into_iter().map(Into::into).collect()),
             ..Default::default()
         };
         self.search(index_name, &request).await
     }
 
     /// Execute a semantic search query (optionally with vector search)
+    ///
+    /// `VectorKind` queries convert with `.into()`.
     async fn semantic_search<T: serde::de::DeserializeOwned>(
         &self,
         index_name: &str,
         query: &str,
         semantic_configuration: &str,
-        vector_queries: Option<Vec<VectorKind>>,
+        vector_queries: Option<Vec<VectorQuery>>,
     ) -> Result<SearchResponse<T>> {
         let request = SearchRequest {
             search: Some(query.to_string()),
@@ -94,14 +171,401 @@ impl SearchTrait for AzureSearchClient {
         index_name: &str,
         query: &str,
         semantic_configuration: &str,
-        vector_queries: Vec<VectorKind>,
+        vector_queries: impl IntoIterator<Item = impl Into<VectorQuery>> + Send,
     ) -> Result<SearchResponse<T>> {
         self.semantic_search(
             index_name,
             query,
             semantic_configuration,
-            Some(vector_queries),
+            Some(vector_queries.into_iter().map(Into::into).collect()),
         )
         .await
     }
 }
+
+/// Apply the client's [`CoveragePolicy`] to a search response
+fn apply_coverage_policy<T>(
+    policy: CoveragePolicy,
+    mut response: SearchResponse<T>,
+    minimum_coverage: Option<u8>,
+) -> Result<SearchResponse<T>> {
+    if let (Some(minimum_coverage), Some(coverage)) = (minimum_coverage, response.coverage) {
+        if coverage < f64::from(minimum_coverage) {
+            match policy {
+                CoveragePolicy::Ignore => {}
+                CoveragePolicy::Warn => {
+                    response.coverage_warning = Some(CoverageWarning {
+                        coverage,
+                        minimum_coverage,
+                    })
+                }
+                CoveragePolicy::Error => {
+                    return Err(Error::InsufficientCoverage {
+                        coverage,
+                        minimum_coverage,
+                    })
+                }
+            }
+        }
+    }
+    Ok(response)
+}
+
+/// Number of results the service returns when `top` is not set
+const DEFAULT_TOP: u64 = 50;
+
+/// Lower `top` so that no more than `remaining` results are requested
+fn limit_top(request: &mut Map<String, Value>, remaining: usize) {
+    let top = request
+        .get("top")
+        .and_then(Value::as_u64)
+        .unwrap_or(DEFAULT_TOP);
+    let remaining = u64::try_from(remaining).unwrap_or(u64::MAX);
+    if remaining < top {
+        request.insert("top".to_string(), remaining.into());
+    }
+}
+
+/// `@search.nextPageParameters` of a response, as sent by the service
+#[derive(Deserialize)]
+struct NextPage {
+    #[serde(rename = "@search.nextPageParameters")]
+    next_page_parameters: Option<Map<String, Value>>,
+}
+
+/// Aborts a spawned task when dropped, e.g. when a stream is abandoned
+struct AbortOnDrop<T>(JoinHandle<T>);
+
+impl<T> Drop for AbortOnDrop<T> {
+    fn drop(&mut self) {
+        self.0.abort();
+    }
+}
+
+/// Send a page request and return the response with the raw next page parameters
+async fn fetch_page<T: serde::de::DeserializeOwned>(
+    client: &AzureSearchClient,
+    index_name: &str,
+    request: &Map<String, Value>,
+) -> Result<(SearchResponse<T>, Option<Map<String, Value>>)> {
+    let path = format!("indexes/{}/docs/search", index_name);
+    let body = client
+        .send_request::<Box<RawValue>, _>(reqwest::Method::POST, &path, Some(request))
+        .await?;
+    let response: SearchResponse<T> = serde_json::from_str(body.get())?;
+    let next: NextPage = serde_json::from_str(body.get())?;
+
+    let minimum_coverage = request
+        .get("minimumCoverage")
+        .and_then(Value::as_u64)
+        .and_then(|coverage| u8::try_from(coverage).ok());
+    let response = apply_coverage_policy(client.coverage_policy(), response, minimum_coverage)?;
+    Ok((response, next.next_page_parameters))
+}
+
+/// Paging state for [`SearchTrait::search_pages`]
+struct PageState<T> {
+    client: AzureSearchClient,
+    index_name: String,
+    /// Body of the last request, or of the next one if not sent yet
+    next: Option<Map<String, Value>>,
+    prefetched: Option<AbortOnDrop<PageResult<T>>>,
+    remaining: Option<usize>,
+    prefetch: bool,
+}
+
+type PageResult<T> = Result<(SearchResponse<T>, Option<Map<String, Value>>)>;
+
+impl<T: serde::de::DeserializeOwned + Send + 'static> PageState<T> {
+    async fn next_page(mut self) -> Result<Option<(SearchResponse<T>, Self)>> {
+        if self.remaining == Some(0) {
+            return Ok(None);
+        }
+
+        let (mut page, next_parameters) = match (self.prefetched.take(), self.next.as_ref()) {
+            (Some(mut handle), _) => (&mut handle.0)
+                .await
+                .map_err(|err| Error::SearchError(format!("prefetch task failed: {}", err)))??,
+            (None, Some(request)) => {
+                fetch_page::<T>(&self.client, &self.index_name, request).await?
+            }
+            (None, None) => return Ok(None),
+        };
+
+        if let Some(remaining) = self.remaining.as_mut() {
+            page.value.truncate(*remaining);
+            *remaining -= page.value.len();
+        }
+
+        // Merge the service's parameters into the previous request
+        self.next = match (self.next.take(), next_parameters) {
+            (Some(mut request), Some(parameters))
+                if self.remaining != Some(0) && !page.value.is_empty() =>
+            {
+                request.extend(parameters);
+                if let Some(remaining) = self.remaining {
+                    limit_top(&mut request, remaining);
+                }
+                Some(request)
+            }
+            _ => None,
+        };
+
+        if self.prefetch {
+            if let Some(request) = self.next.clone() {
+                let client = self.client.clone();
+                let index_name = self.index_name.clone();
+                self.prefetched = Some(AbortOnDrop(tokio::spawn(async move {
+                    fetch_page::<T>(&client, &index_name, &request).await
+                })));
+            }
+        }
+
+        Ok(Some((page, self)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    use futures::StreamExt;
+    use serde_json::json;
+
+    use super::*;
+    use crate::models::search::VectorKind;
+    use crate::test_util::{TestResponse, TestServer};
+
+    /// Index of `total` documents that returns at most `page_size` per response,
+    /// asking for the rest through `@search.nextPageParameters`
+    async fn paged_index(total: u64, page_size: u64) -> TestServer {
+        TestServer::start(move |request| {
+            let body: Value = serde_json::from_str(&request.body).unwrap();
+            let skip = body["skip"].as_u64().unwrap_or(0);
+            let top = body["top"].as_u64().unwrap_or(DEFAULT_TOP);
+            let end = total.min(skip + top.min(page_size));
+            let value: Vec<Value> = (skip..end)
+                .map(|id| json!({"@search.score": 1.0, "id": id}))
+                .collect();
+
+            let mut response = json!({ "value": value });
+            if end < total.min(skip + top) {
+                let mut next = body.clone();
+                next["skip"] = json!(end);
+                next["top"] = json!(skip + top - end);
+                // A parameter the crate doesn't model
+                next["queryLanguage"] = json!("en-us");
+                response["@search.nextPageParameters"] = next;
+            }
+            TestResponse::json(200, response)
+        })
+        .await
+    }
+
+    fn client(server: &TestServer) -> AzureSearchClient {
+        AzureSearchClient::new(&server.url, "key", "2024-07-01", None).unwrap()
+    }
+
+    fn request_bodies(server: &TestServer) -> Vec<Value> {
+        server
+            .requests()
+            .iter()
+            .map(|request| serde_json::from_str(&request.body).unwrap())
+            .collect()
+    }
+
+    async fn ids(stream: impl Stream<Item = Result<SearchResult<Value>>>) -> Vec<u64> {
+        stream
+            .map(|result| result.unwrap().document["id"].as_u64().unwrap())
+            .collect()
+            .await
+    }
+
+    #[tokio::test]
+    async fn stream_follows_next_page_parameters() {
+        let server = paged_index(5, 2).await;
+        let request = SearchRequest {
+            search: Some("*".to_string()),
+            top: Some(10),
+            ..Default::default()
+        };
+
+        let stream = client(&server).search_stream("hotels", request, Default::default());
+        assert_eq!(ids(stream).await, [0, 1, 2, 3, 4]);
+
+        let bodies = request_bodies(&server);
+        assert_eq!(bodies.len(), 3);
+        assert_eq!(bodies[1]["skip"], 2);
+        assert_eq!(bodies[1]["search"], "*");
+        assert_eq!(bodies[1]["queryLanguage"], "en-us");
+        assert_eq!(bodies[2]["skip"], 4);
+    }
+
+    #[tokio::test]
+    async fn prefetched_stream_returns_every_result() {
+        let server = paged_index(5, 2).await;
+        let request = SearchRequest {
+            top: Some(10),
+            ..Default::default()
+        };
+        let options = SearchPagingOptions::default().prefetch(true);
+
+        let stream = client(&server).search_stream("hotels", request, options);
+        assert_eq!(ids(stream).await, [0, 1, 2, 3, 4]);
+        assert_eq!(server.request_count(), 3);
+    }
+
+    #[tokio::test]
+    async fn max_results_lowers_top() {
+        let server = paged_index(100, 2).await;
+        let request = SearchRequest {
+            top: Some(10),
+            ..Default::default()
+        };
+        let options = SearchPagingOptions::default().max_results(3);
+
+        let stream = client(&server).search_stream("hotels", request, options);
+        assert_eq!(ids(stream).await, [0, 1, 2]);
+
+        let bodies = request_bodies(&server);
+        let tops: Vec<&Value> = bodies.iter().map(|body| &body["top"]).collect();
+        assert_eq!(tops, [&json!(3), &json!(1)]);
+    }
+
+    #[tokio::test]
+    async fn max_results_below_default_page_size() {
+        let server = paged_index(100, 1000).await;
+        let options = SearchPagingOptions::default().max_results(7);
+
+        let stream = client(&server).search_stream("hotels", SearchRequest::default(), options);
+        assert_eq!(ids(stream).await, [0, 1, 2, 3, 4, 5, 6]);
+        assert_eq!(request_bodies(&server)[0]["top"], 7);
+    }
+
+    #[tokio::test]
+    async fn pages_end_without_next_page_parameters() {
+        let server = paged_index(3, 1000).await;
+
+        let pages: Vec<SearchResponse<Value>> = client(&server)
+            .search_pages("hotels", SearchRequest::default(), Default::default())
+            .map(Result::unwrap)
+            .collect()
+            .await;
+        assert_eq!(pages.len(), 1);
+        assert_eq!(pages[0].value.len(), 3);
+    }
+
+    #[tokio::test]
+    async fn dropped_guard_aborts_task() {
+        let finished = Arc::new(AtomicBool::new(false));
+        let task = {
+            let finished = finished.clone();
+            tokio::spawn(async move {
+                tokio::time::sleep(Duration::from_millis(50)).await;
+                finished.store(true, Ordering::SeqCst);
+            })
+        };
+
+        drop(AbortOnDrop(task));
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        assert!(!finished.load(Ordering::SeqCst));
+    }
+
+    #[tokio::test]
+    async fn coverage_policy() {
+        let server = TestServer::start(|_| {
+            TestResponse::json(200, json!({"value": [], "@search.coverage": 80.0}))
+        })
+        .await;
+        let request = SearchRequest {
+            minimum_coverage: Some(90),
+            ..Default::default()
+        };
+
+        let response: SearchResponse<Value> =
+            client(&server).search("hotels", &request).await.unwrap();
+        assert!(response.coverage_warning.is_none());
+
+        let warned: SearchResponse<Value> = client(&server)
+            .with_coverage_policy(CoveragePolicy::Warn)
+            .search("hotels", &request)
+            .await
+            .unwrap();
+        assert_eq!(
+            warned.coverage_warning,
+            Some(CoverageWarning {
+                coverage: 80.0,
+                minimum_coverage: 90
+            })
+        );
+
+        let err = client(&server)
+            .with_coverage_policy(CoveragePolicy::Error)
+            .search::<Value>("hotels", &request)
+            .await
+            .unwrap_err();
+        assert!(matches!(err, Error::InsufficientCoverage { .. }));
+    }
+
+    #[tokio::test]
+    async fn vector_helpers_accept_any_vector_query_input() {
+        let server = TestServer::start(|_| TestResponse::json(200, json!({ "value": [] }))).await;
+        let client = client(&server);
+        let kind = VectorKind::TextQuery {
+            kind: "text".to_string(),
+            text: "ocean view".to_string(),
+            fields: vec!["embedding".to_string()],
+            k: 3,
+            weight: Some(2.0),
+        };
+
+        client
+            .vector_search::<Value>("hotels", [kind.clone()])
+            .await
+            .unwrap();
+        client
+            .vector_search::<Value>(
+                "hotels",
+                vec![VectorQuery::vector(vec![0.5], ["embedding"])],
+            )
+            .await
+            .unwrap();
+        client
+            .semantic_search::<Value>(
+                "hotels",
+                "ocean",
+                "default",
+                Some(vec![kind.clone().into()]),
+            )
+            .await
+            .unwrap();
+        client
+            .semantic_search::<Value>("hotels", "ocean", "default", None)
+            .await
+            .unwrap();
+        client
+            .hybrid_search::<Value>("hotels", "ocean", "default", Some(kind))
+            .await
+            .unwrap();
+
+        let bodies = request_bodies(&server);
+        let text_query = json!({
+            "kind": "text",
+            "text": "ocean view",
+            "fields": "embedding",
+            "k": 3,
+            "weight": 2.0
+        });
+        assert_eq!(bodies[0]["vectorQueries"], json!([text_query]));
+        assert_eq!(
+            bodies[1]["vectorQueries"],
+            json!([{ "kind": "vector", "vector": [0.5], "fields": "embedding" }])
+        );
+        assert_eq!(bodies[2]["vectorQueries"], json!([text_query]));
+        assert_eq!(bodies[2]["queryType"], "semantic");
+        assert!(bodies[3].get("vectorQueries").is_none());
+        assert_eq!(bodies[4]["vectorQueries"], json!([text_query]));
+    }
+}
@@ -0,0 +1,16 @@
+use crate::{client::AzureSearchClient, error::Result, models::stats::ServiceStatistics};
+
+pub trait ServiceTrait {
+    /// Get resource usage, quotas and limits of the search service
+    fn get_service_statistics(
+        &self,
+    ) -> impl std::future::Future<Output = Result<ServiceStatistics>> + Send;
+}
+
+impl ServiceTrait for AzureSearchClient {
+    /// Get resource usage, quotas and limits of the search service
+    async fn get_service_statistics(&self) -> Result<ServiceStatistics> {
+        self.send_request::<ServiceStatistics, ()>(reqwest::Method::GET, "servicestats", None)
+            .await
+    }
+}
@@ -0,0 +1,31 @@
+use crate::{
+    client::AzureSearchClient,
+    error::Result,
+    models::suggest::{SuggestRequest, SuggestResponse},
+};
+
+pub trait SuggestTrait {
+    /// Suggest documents matching partial search text
+    fn suggest<T: serde::de::DeserializeOwned>(
+        &self,
+        index_name: &str,
+        request: &SuggestRequest,
+    ) -> impl std::future::Future<Output = Result<SuggestResponse<T>>> + Send;
+}
+
+impl SuggestTrait for AzureSearchClient {
+    /// Suggest documents matching partial search text
+    async fn suggest<T: serde::de::DeserializeOwned>(
+        &self,
+        index_name: &str,
+        request: &SuggestRequest,
+    ) -> Result<SuggestResponse<T>> {
+        let path = format!("indexes/{}/docs/suggest", index_name);
+        self.send_request::<SuggestResponse<T>, SuggestRequest>(
+            reqwest::Method::POST,
+            &path,
+            Some(request),
+        )
+        .await
+    }
+}
@@ -0,0 +1,320 @@
+use std::collections::hash_map::RandomState;
+use std::hash::{BuildHasher, Hasher};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use reqwest::header::HeaderMap;
+use reqwest::{Method, StatusCode};
+
+/// POST endpoints that only read data or apply key-addressed writes, and are
+/// therefore safe to resend after a failure.
+const RETRY_SAFE_POST_SUFFIXES: &[&str] = &[
+    "/docs/search",
+    "/docs/suggest",
+    "/docs/autocomplete",
+    "/docs/index",
+    "/analyze",
+];
+
+/// Retry policy applied to every request sent through [`crate::AzureSearchClient`]
+#[derive(Debug, Clone)]
+pub struct RetryPolicy {
+    /// Maximum number of attempts, including the first one (1 disables retries)
+    pub max_attempts: u32,
+    /// Delay before the first retry; doubled on every following attempt
+    pub base_delay: Duration,
+    /// Upper bound for the computed backoff delay
+    pub max_delay: Duration,
+    /// Randomize each delay between 50% and 100% of its computed value
+    pub jitter: bool,
+    /// Use the `retry-after-ms` / `Retry-After` response headers when present,
+    /// capped at `max_delay`
+    pub honor_retry_after: bool,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 4,
+            base_delay: Duration::from_millis(800),
+            max_delay: Duration::from_secs(60),
+            jitter: true,
+            honor_retry_after: true,
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// A policy that never retries
+    pub fn none() -> Self {
+        Self {
+            max_attempts: 1,
+            ..Default::default()
+        }
+    }
+
+    /// Set the maximum number of attempts
+    pub fn with_max_attempts(mut self, max_attempts: u32) -> Self {
+        self.max_attempts = max_attempts.max(1);
+        self
+    }
+
+    /// Set the base delay
+    pub fn with_base_delay(mut self, base_delay: Duration) -> Self {
+        self.base_delay = base_delay;
+        self
+    }
+
+    /// Set the maximum backoff delay
+    pub fn with_max_delay(mut self, max_delay: Duration) -> Self {
+        self.max_delay = max_delay;
+        self
+    }
+
+    /// Enable or disable jitter
+    pub fn with_jitter(mut self, jitter: bool) -> Self {
+        self.jitter = jitter;
+        self
+    }
+
+    /// Enable or disable honoring `Retry-After` headers
+    pub fn with_honor_retry_after(mut self, honor_retry_after: bool) -> Self {
+        self.honor_retry_after = honor_retry_after;
+        self
+    }
+
+    /// Whether a response with this status should be retried
+    pub fn is_retriable_status(status: StatusCode) -> bool {
+        matches!(
+            status,
+            StatusCode::REQUEST_TIMEOUT
+                | StatusCode::TOO_MANY_REQUESTS
+                | StatusCode::INTERNAL_SERVER_ERROR
+                | StatusCode::BAD_GATEWAY
+                | StatusCode::SERVICE_UNAVAILABLE
+                | StatusCode::GATEWAY_TIMEOUT
+        )
+    }
+
+    /// Whether a transport error is transient and worth retrying
+    pub fn is_retriable_error(error: &reqwest::Error) -> bool {
+        error.is_connect() || error.is_timeout()
+    }
+
+    /// Whether a request can be resent without changing its outcome
+    pub fn is_retry_safe(method: &Method, path: &str) -> bool {
+        match *method {
+            Method::GET | Method::HEAD | Method::OPTIONS | Method::PUT | Method::DELETE => true,
+            Method::POST => {
+                let path = path.split('?').next().unwrap_or(path).trim_end_matches('/');
+                RETRY_SAFE_POST_SUFFIXES
+                    .iter()
+                    .any(|suffix| path.ends_with(suffix))
+            }
+            _ => false,
+        }
+    }
+
+    /// Delay to wait before the given retry (1 for the first retry)
+    pub fn delay_for(&self, retry: u32, headers: Option<&HeaderMap>) -> Duration {
+        if self.honor_retry_after {
+            if let Some(delay) = headers.and_then(|headers| retry_after(headers, SystemTime::now()))
+            {
+                return delay.min(self.max_delay);
+            }
+        }
+
+        let factor = 2u32.saturating_pow(retry.saturating_sub(1));
+        let delay = self.base_delay.saturating_mul(factor).min(self.max_delay);
+        if self.jitter {
+            // Scale by a random factor in [0.5, 1.0)
+            let fraction = 0.5 + (random_u64() % 1000) as f64 / 2000.0;
+            delay.mul_f64(fraction)
+        } else {
+            delay
+        }
+    }
+}
+
+/// Parse the delay requested by the service, preferring millisecond precision
+///
+/// `Retry-After` is either a number of seconds or an HTTP date, which is
+/// measured from `now`.
+fn retry_after(headers: &HeaderMap, now: SystemTime) -> Option<Duration> {
+    for name in ["retry-after-ms", "x-ms-retry-after-ms"] {
+        if let Some(ms) = header_str(headers, name).and_then(|value| value.parse().ok()) {
+            return Some(Duration::from_millis(ms));
+        }
+    }
+    let value = header_str(headers, "retry-after")?;
+    match value.parse() {
+        Ok(seconds) => Some(Duration::from_secs(seconds)),
+        Err(_) => {
+            let date = parse_http_date(value)?;
+            Some(date.duration_since(now).unwrap_or(Duration::ZERO))
+        }
+    }
+}
+
+fn header_str<'a>(headers: &'a HeaderMap, name: &str) -> Option<&'a str> {
+    Some(headers.get(name)?.to_str().ok()?.trim())
+}
+
+/// Parse an IMF-fixdate such as `Sun, 06 Nov 1994 08:49:37 GMT`
+fn parse_http_date(value: &str) -> Option<SystemTime> {
+    const MONTHS: [&str; 12] = [
+        "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+    ];
+
+    let (_weekday, rest) = value.split_once(", ")?;
+    let mut parts = rest.split(' ');
+    let day: u32 = parts.next()?.parse().ok()?;
+    let month = parts.next()?;
+    let month = MONTHS.iter().position(|name| *name == month)? as u32 + 1;
+    let year: i64 = parts.next()?.parse().ok()?;
+    let mut time = parts.next()?.split(':');
+    let hour: u64 = time.next()?.parse().ok()?;
+    let minute: u64 = time.next()?.parse().ok()?;
+    let second: u64 = time.next()?.parse().ok()?;
+    if parts.next()? != "GMT" || parts.next().is_some() || time.next().is_some() {
+        return None;
+    }
+    if !(1..=31).contains(&day) || hour > 23 || minute > 59 || second > 60 {
+        return None;
+    }
+
+    let days = u64::try_from(days_from_civil(year, month, day)).ok()?;
+    let seconds = days * 86_400 + hour * 3_600 + minute * 60 + second;
+    Some(UNIX_EPOCH + Duration::from_secs(seconds))
+}
+
+/// Days since 1970-01-01 of a proleptic Gregorian date
+fn days_from_civil(year: i64, month: u32, day: u32) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let month = i64::from(month);
+    let day_of_year = (153 * (month + if month > 2 { -3 } else { 9 }) + 2) / 5 + i64::from(day) - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
+fn random_u64() -> u64 {
+    RandomState::new().build_hasher().finish()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn headers(pairs: &[(&'static str, &str)]) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        for (name, value) in pairs {
+            headers.insert(*name, value.parse().unwrap());
+        }
+        headers
+    }
+
+    #[test]
+    fn backoff_doubles_up_to_max_delay() {
+        let policy = RetryPolicy::default()
+            .with_jitter(false)
+            .with_base_delay(Duration::from_millis(100))
+            .with_max_delay(Duration::from_millis(500));
+
+        let delays: Vec<_> = (1..=5).map(|retry| policy.delay_for(retry, None)).collect();
+        assert_eq!(
+            delays,
+            [100, 200, 400, 500, 500]
+                .map(Duration::from_millis)
+                .to_vec()
+        );
+        assert_eq!(policy.delay_for(u32::MAX, None), Duration::from_millis(500));
+    }
+
+    #[test]
+    fn jitter_stays_between_half_and_full_delay() {
+        let policy = RetryPolicy::default().with_base_delay(Duration::from_millis(1000));
+        for retry in 1..=3 {
+            let full = Duration::from_millis(1000 * 2u64.pow(retry - 1));
+            for _ in 0..200 {
+                let delay = policy.delay_for(retry, None);
+                assert!(delay >= full / 2 && delay < full, "{:?}", delay);
+            }
+        }
+    }
+
+    #[test]
+    fn retry_after_prefers_milliseconds() {
+        let now = SystemTime::now();
+        let parsed = retry_after(
+            &headers(&[("retry-after", "7"), ("retry-after-ms", "1500")]),
+            now,
+        );
+        assert_eq!(parsed, Some(Duration::from_millis(1500)));
+
+        let parsed = retry_after(&headers(&[("x-ms-retry-after-ms", "250")]), now);
+        assert_eq!(parsed, Some(Duration::from_millis(250)));
+
+        let parsed = retry_after(&headers(&[("retry-after", " 7 ")]), now);
+        assert_eq!(parsed, Some(Duration::from_secs(7)));
+
+        assert_eq!(retry_after(&headers(&[("retry-after", "soon")]), now), None);
+        assert_eq!(retry_after(&HeaderMap::new(), now), None);
+    }
+
+    #[test]
+    fn retry_after_http_date() {
+        let date = parse_http_date("Sun, 06 Nov 1994 08:49:37 GMT").unwrap();
+        assert_eq!(date, UNIX_EPOCH + Duration::from_secs(784_111_777));
+
+        let now = UNIX_EPOCH + Duration::from_secs(784_111_767);
+        let parsed = retry_after(
+            &headers(&[("retry-after", "Sun, 06 Nov 1994 08:49:37 GMT")]),
+            now,
+        );
+        assert_eq!(parsed, Some(Duration::from_secs(10)));
+
+        // Dates in the past mean "retry now"
+        let later = now + Duration::from_secs(3600);
+        let parsed = retry_after(
+            &headers(&[("retry-after", "Sun, 06 Nov 1994 08:49:37 GMT")]),
+            later,
+        );
+        assert_eq!(parsed, Some(Duration::ZERO));
+
+        assert_eq!(parse_http_date("Sun, 06 Nov 1994 08:49:37 PST"), None);
+        assert_eq!(parse_http_date("Sun, 32 Nov 1994 08:49:37 GMT"), None);
+        assert_eq!(parse_http_date("Sunday, 06-Nov-94 08:49:37 GMT"), None);
+    }
+
+    #[test]
+    fn retry_after_is_capped_at_max_delay() {
+        let policy = RetryPolicy::default().with_max_delay(Duration::from_secs(30));
+        let delay = policy.delay_for(1, Some(&headers(&[("retry-after", "86400")])));
+        assert_eq!(delay, Duration::from_secs(30));
+
+        let delay = policy.delay_for(1, Some(&headers(&[("retry-after", "2")])));
+        assert_eq!(delay, Duration::from_secs(2));
+
+        let ignored = policy
+            .clone()
+            .with_honor_retry_after(false)
+            .with_jitter(false)
+            .delay_for(1, Some(&headers(&[("retry-after", "2")])));
+        assert_eq!(ignored, policy.base_delay);
+    }
+
+    #[test]
+    fn retry_safe_requests() {
+        assert!(RetryPolicy::is_retry_safe(&Method::GET, "indexes/hotels"));
+        assert!(RetryPolicy::is_retry_safe(
+            &Method::POST,
+            "indexes/hotels/docs/search?api-version=2024-07-01"
+        ));
+        assert!(!RetryPolicy::is_retry_safe(&Method::POST, "indexes"));
+        assert!(!RetryPolicy::is_retry_safe(
+            &Method::PATCH,
+            "indexes/hotels"
+        ));
+    }
+}